@@ -1,6 +1,7 @@
 use node_template_runtime::{
 	AccountId, AuraConfig, BalancesConfig, CouncilConfig, PanelConfig, CommitteeConfig, BoardConfig, GenesisConfig, GrandpaConfig, Signature,
-	SudoConfig, SystemConfig, WASM_BINARY,
+	PanelSortitionConfig, CommitteeSortitionConfig, ReviewersConfig, SudoConfig, PanelField,
+	CommitteeField, PanelSortitionSize, CommitteeSortitionSize, SystemConfig, WASM_BINARY,
 };
 use sc_service::ChainType;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
@@ -33,6 +34,13 @@ where
 	AccountPublic::from(get_from_seed::<TPublic>(seed)).into_account()
 }
 
+/// Generate the account IDs of `count` development reviewers, from the seed `{prefix}1` on.
+fn reviewer_accounts_from_seed(prefix: &str, count: u32) -> Vec<AccountId> {
+	(1..=count)
+		.map(|i| get_account_id_from_seed::<sr25519::Public>(&format!("{}{}", prefix, i)))
+		.collect()
+}
+
 /// Generate an Aura authority key.
 pub fn authority_keys_from_seed(s: &str) -> (AuraId, GrandpaId) {
 	(get_from_seed::<AuraId>(s), get_from_seed::<GrandpaId>(s))
@@ -132,6 +140,28 @@ fn testnet_genesis(
 	endowed_accounts: Vec<AccountId>,
 	_enable_println: bool,
 ) -> GenesisConfig {
+	// The pools hold twice as many reviewers as a panel, so that every draw rotates members. The
+	// development reviewers are endowed so that they can take part.
+	let panel_reviewers = reviewer_accounts_from_seed("PanelReviewer", 8);
+	let committee_reviewers = reviewer_accounts_from_seed("CommitteeReviewer", 12);
+	let panel_pool: Vec<AccountId> = vec![
+		// Account: Jeremiah
+		hex!["9c344c75bbf594b662a58d4c64181d2d01c937ea1f1c4e1b305069c844f4f644"].into(),
+		// Account: Carlos
+		hex!["6a18b5185e177e1f70e11901d5cb863f9ef756ef3bab56a48d47d7738611b808"].into(),
+	]
+	.into_iter()
+	.chain(panel_reviewers.iter().cloned())
+	.collect();
+	let committee_pool: Vec<AccountId> = vec![
+		// Account: Tyrell
+		hex!["a6486e313a0d692c409ef2e87272e7a1637e030e4582313130e75ea7a3d67c0f"].into(),
+		// Account: Marian
+		hex!["2a6dda91b70e6b2f5689daa67d95d6c6ddea44e12e9c9d98d40c3daafdced87b"].into(),
+	]
+	.into_iter()
+	.chain(committee_reviewers.iter().cloned())
+	.collect();
 	GenesisConfig {
 		system: SystemConfig {
 			// Add Wasm runtime to storage.
@@ -140,7 +170,13 @@ fn testnet_genesis(
 		},
 		balances: BalancesConfig {
 			// Configure endowed accounts with initial balance of 1 << 60.
-			balances: endowed_accounts.iter().cloned().map(|k| (k, 1 << 60)).collect(),
+			balances: endowed_accounts
+				.iter()
+				.chain(panel_reviewers.iter())
+				.chain(committee_reviewers.iter())
+				.cloned()
+				.map(|k| (k, 1 << 60))
+				.collect(),
 		},
 		aura: AuraConfig {
 			authorities: initial_authorities.iter().map(|x| (x.0.clone())).collect(),
@@ -161,24 +197,9 @@ fn testnet_genesis(
 			],
 			phantom: Default::default(),
 		},
-		panel: PanelConfig {
-			members: vec![
-				// Account: Jeremiah
-				hex!["9c344c75bbf594b662a58d4c64181d2d01c937ea1f1c4e1b305069c844f4f644"].into(),
-				// Account: Carlos
-				hex!["6a18b5185e177e1f70e11901d5cb863f9ef756ef3bab56a48d47d7738611b808"].into(),
-			],
-			phantom: Default::default(),
-		},
-		committee: CommitteeConfig {
-			members: vec![
-				// Account: Tyrell
-				hex!["a6486e313a0d692c409ef2e87272e7a1637e030e4582313130e75ea7a3d67c0f"].into(),
-				// Account: Marian
-				hex!["2a6dda91b70e6b2f5689daa67d95d6c6ddea44e12e9c9d98d40c3daafdced87b"].into(),
-			],
-			phantom: Default::default(),
-		},
+		// The Panel and the Committee are drawn by sortition, which sets their first members.
+		panel: PanelConfig { members: vec![], phantom: Default::default() },
+		committee: CommitteeConfig { members: vec![], phantom: Default::default() },
 		board: BoardConfig {
 			members: vec![
 				// Account: Yakub
@@ -188,5 +209,32 @@ fn testnet_genesis(
 			],
 			phantom: Default::default(),
 		},
+		// The first reviewers are approved at genesis, their credentials were checked off-chain.
+		reviewers: ReviewersConfig {
+			reviewers: panel_pool
+				.iter()
+				.map(|who| (who.clone(), Vec::new(), vec![PanelField::get()]))
+				.chain(
+					committee_pool
+						.iter()
+						.map(|who| (who.clone(), Vec::new(), vec![CommitteeField::get()])),
+				)
+				.collect(),
+		},
+		// The first members of each panel serve until the first draw.
+		panel_sortition: PanelSortitionConfig {
+			panel: panel_pool.iter().take(PanelSortitionSize::get() as usize).cloned().collect(),
+			pool: panel_pool,
+			phantom: Default::default(),
+		},
+		committee_sortition: CommitteeSortitionConfig {
+			panel: committee_pool
+				.iter()
+				.take(CommitteeSortitionSize::get() as usize)
+				.cloned()
+				.collect(),
+			pool: committee_pool,
+			phantom: Default::default(),
+		},
 	}
 }
@@ -0,0 +1,179 @@
+//! RPC interface for the PublicaFides pallet.
+//!
+//! Exposes the `PublicaFidesApi` runtime API as `publicaFides_*` methods, so that frontends get
+//! typed content and claims instead of decoding raw storage.
+
+use std::{marker::PhantomData, sync::Arc};
+
+use codec::Codec;
+use jsonrpc_core::{Error as RpcError, ErrorCode, Result};
+use jsonrpc_derive::rpc;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::{generic::BlockId, traits::Block as BlockT, Permill};
+
+use publicafides_runtime_api::{ClaimId, ClaimInfo, ClaimStatus, ClaimSummary, ContentInfo};
+pub use publicafides_runtime_api::{PublicaFidesApi as PublicaFidesRuntimeApi, MAX_PAGE_SIZE};
+
+/// Error code of runtime calls that failed.
+const RUNTIME_ERROR: i64 = 1;
+
+/// PublicaFides RPC methods.
+#[rpc]
+pub trait PublicaFidesApi<BlockHash, ContentId> {
+	/// Returns the content with its claims, if it exists.
+	#[rpc(name = "publicaFides_content")]
+	fn content(
+		&self,
+		content_id: ContentId,
+		at: Option<BlockHash>,
+	) -> Result<Option<ContentInfo<ContentId>>>;
+
+	/// Returns the claims with the given status among the `count` claim ids starting at `start`,
+	/// ordered by id. `count` may be at most `MAX_PAGE_SIZE`.
+	#[rpc(name = "publicaFides_claimsByStatus")]
+	fn claims_by_status(
+		&self,
+		status: ClaimStatus,
+		start: ClaimId,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<ClaimInfo>>;
+
+	/// Returns the score of the content, if it exists and was scored.
+	#[rpc(name = "publicaFides_score")]
+	fn score(&self, content_id: ContentId, at: Option<BlockHash>) -> Result<Option<Permill>>;
+
+	/// Returns up to `count` content items with an id of at least `start`, ordered by id.
+	/// `count` may be at most `MAX_PAGE_SIZE`.
+	#[rpc(name = "publicaFides_contentPage")]
+	fn content_page(
+		&self,
+		start: ContentId,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<ContentInfo<ContentId>>>;
+
+	/// Returns the approved plain-language summaries of the claims raised in the content, ordered
+	/// as the claims were linked.
+	#[rpc(name = "publicaFides_publicSummaries")]
+	fn public_summaries(
+		&self,
+		content_id: ContentId,
+		at: Option<BlockHash>,
+	) -> Result<Vec<ClaimSummary>>;
+}
+
+/// Implementation of the PublicaFides RPC methods on top of a client.
+pub struct PublicaFides<C, Block> {
+	client: Arc<C>,
+	_marker: PhantomData<Block>,
+}
+
+impl<C, Block> PublicaFides<C, Block> {
+	/// Creates a new instance of the PublicaFides RPC handler.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+impl<C, Block> PublicaFides<C, Block>
+where
+	Block: BlockT,
+	C: HeaderBackend<Block>,
+{
+	/// The block to query, defaulting to the best block.
+	fn block_id(&self, at: Option<<Block as BlockT>::Hash>) -> BlockId<Block> {
+		BlockId::hash(at.unwrap_or_else(|| self.client.info().best_hash))
+	}
+}
+
+impl<C, Block, ContentId> PublicaFidesApi<<Block as BlockT>::Hash, ContentId>
+	for PublicaFides<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: PublicaFidesRuntimeApi<Block, ContentId>,
+	ContentId: Codec,
+{
+	fn content(
+		&self,
+		content_id: ContentId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<ContentInfo<ContentId>>> {
+		self.client
+			.runtime_api()
+			.content(&self.block_id(at), content_id)
+			.map_err(|e| runtime_error("Unable to query content.", e))
+	}
+
+	fn claims_by_status(
+		&self,
+		status: ClaimStatus,
+		start: ClaimId,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<ClaimInfo>> {
+		check_page_size(count)?;
+		self.client
+			.runtime_api()
+			.claims_by_status(&self.block_id(at), status, start, count)
+			.map_err(|e| runtime_error("Unable to query claims.", e))
+	}
+
+	fn score(
+		&self,
+		content_id: ContentId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Option<Permill>> {
+		self.client
+			.runtime_api()
+			.score(&self.block_id(at), content_id)
+			.map_err(|e| runtime_error("Unable to query score.", e))
+	}
+
+	fn content_page(
+		&self,
+		start: ContentId,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<ContentInfo<ContentId>>> {
+		check_page_size(count)?;
+		self.client
+			.runtime_api()
+			.content_page(&self.block_id(at), start, count)
+			.map_err(|e| runtime_error("Unable to query content.", e))
+	}
+
+	fn public_summaries(
+		&self,
+		content_id: ContentId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<ClaimSummary>> {
+		self.client
+			.runtime_api()
+			.public_summaries(&self.block_id(at), content_id)
+			.map_err(|e| runtime_error("Unable to query summaries.", e))
+	}
+}
+
+/// Rejects pages larger than `MAX_PAGE_SIZE`.
+fn check_page_size(count: u32) -> Result<()> {
+	if count > MAX_PAGE_SIZE {
+		return Err(RpcError {
+			code: ErrorCode::InvalidParams,
+			message: format!("Page size can be at most {}.", MAX_PAGE_SIZE),
+			data: None,
+		})
+	}
+	Ok(())
+}
+
+/// Converts a failed runtime call into an RPC error.
+fn runtime_error(message: &str, error: impl std::fmt::Debug) -> RpcError {
+	RpcError {
+		code: ErrorCode::ServerError(RUNTIME_ERROR),
+		message: message.into(),
+		data: Some(format!("{:?}", error).into()),
+	}
+}
@@ -0,0 +1,28 @@
+//! Runtime API definition for the PublicaFides pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::Permill;
+use sp_std::vec::Vec;
+
+pub use publicafides::{ClaimId, ClaimInfo, ClaimStatus, ClaimSummary, ContentInfo, MAX_PAGE_SIZE};
+
+sp_api::decl_runtime_apis! {
+	/// Queries of the content and claims under review.
+	pub trait PublicaFidesApi<ContentId> where
+		ContentId: Codec,
+	{
+		/// The content with its claims, if it exists.
+		fn content(content_id: ContentId) -> Option<ContentInfo<ContentId>>;
+		/// The claims with the given status among the `count` claim ids starting at `start`,
+		/// ordered by id.
+		fn claims_by_status(status: ClaimStatus, start: ClaimId, count: u32) -> Vec<ClaimInfo>;
+		/// The score of the content, if it exists and was scored.
+		fn score(content_id: ContentId) -> Option<Permill>;
+		/// Up to `count` content items with an id of at least `start`, ordered by id.
+		fn content_page(start: ContentId, count: u32) -> Vec<ContentInfo<ContentId>>;
+		/// The approved summaries of the claims of the content whose verdict still stands.
+		fn public_summaries(content_id: ContentId) -> Vec<ClaimSummary>;
+	}
+}
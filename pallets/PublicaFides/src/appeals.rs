@@ -0,0 +1,98 @@
+//! Appeals against the final verdicts on claims.
+//!
+//! Anyone can appeal a final verdict with `appeal_claim`, which reserves `AppealBond` from the
+//! appellant and moves the claim to `UnderAppeal`. While under appeal, the claim doesn't count
+//! towards the scores of its content, whether its review is still open or closed. The `AppealOrigin`, e.g. the Board, then re-decides the
+//! claim with `decide_appeal`. If the new verdict differs from the appealed one, the bond is
+//! returned. Otherwise the appeal is dismissed and the bond is slashed to `SpamSlashed`.
+//!
+//! Every final verdict is kept in `VerdictHistory`. A claim can be appealed until `MaxVerdicts`
+//! verdicts were given in its current review, counting the first verdict and those given on
+//! appeal. A re-review, see `reviews`, starts a new review of the claim.
+
+use crate::{BalanceOf, ClaimId, ClaimStatus, Config, Event, Pallet, VerdictCount, VerdictHistory};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Get, Imbalance, OnUnbalanced, ReservableCurrency};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// A final verdict on a claim.
+#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct Verdict<BlockNumber> {
+	/// The final status the claim was given.
+	pub status: ClaimStatus,
+	/// The block in which the verdict was given.
+	pub decided_at: BlockNumber,
+	/// Whether the verdict was given on appeal.
+	pub on_appeal: bool,
+}
+
+/// An appeal against the verdict on a claim which wasn't decided yet.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct Appeal<AccountId, Balance> {
+	/// The account which appealed and reserved the bond.
+	pub appellant: AccountId,
+	/// The reserved bond.
+	pub bond: Balance,
+	/// The appealed status of the claim.
+	pub verdict: ClaimStatus,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Adds the final `status` of the claim to its verdict history and schedules the re-review
+	/// of the verdict.
+	pub(crate) fn record_verdict(claim_id: ClaimId, status: ClaimStatus, on_appeal: bool) {
+		let decided_at = frame_system::Pallet::<T>::block_number();
+		let index = VerdictCount::<T>::get(claim_id);
+		VerdictHistory::<T>::insert(claim_id, index, Verdict { status, decided_at, on_appeal });
+		VerdictCount::<T>::insert(claim_id, index.saturating_add(1));
+		Self::schedule_review(claim_id, decided_at);
+	}
+
+	/// The final verdicts on the claim, oldest first.
+	pub fn verdict_history(claim_id: ClaimId) -> Vec<Verdict<T::BlockNumber>> {
+		(0..VerdictCount::<T>::get(claim_id))
+			.filter_map(|index| VerdictHistory::<T>::get(claim_id, index))
+			.collect()
+	}
+
+	/// The last final verdict on the claim, if any.
+	pub fn last_verdict(claim_id: ClaimId) -> Option<Verdict<T::BlockNumber>> {
+		let count = VerdictCount::<T>::get(claim_id);
+		VerdictHistory::<T>::get(claim_id, count.checked_sub(1)?)
+	}
+
+	/// The number of verdicts given in the current review of the claim: the last verdict not
+	/// given on appeal, and all verdicts given on appeal since. Counts no further than
+	/// `MaxVerdicts`, as no more verdicts are given in one review.
+	pub fn verdicts_in_review(claim_id: ClaimId) -> u32 {
+		let count = VerdictCount::<T>::get(claim_id);
+		let mut in_review = 0;
+		for index in (0..count).rev().take(T::MaxVerdicts::get() as usize) {
+			in_review += 1;
+			match VerdictHistory::<T>::get(claim_id, index) {
+				Some(verdict) if verdict.on_appeal => continue,
+				_ => break,
+			}
+		}
+		in_review
+	}
+
+	/// Returns the bond of the appeal if the claim was given a new `status`, or slashes it to
+	/// `SpamSlashed` otherwise.
+	pub(crate) fn settle_appeal(
+		claim_id: ClaimId,
+		appeal: Appeal<T::AccountId, BalanceOf<T>>,
+		status: ClaimStatus,
+	) {
+		if status != appeal.verdict {
+			T::Currency::unreserve(&appeal.appellant, appeal.bond);
+			Self::deposit_event(Event::AppealUpheld(claim_id, status));
+		} else {
+			let (imbalance, _) = T::Currency::slash_reserved(&appeal.appellant, appeal.bond);
+			let slashed = imbalance.peek();
+			T::SpamSlashed::on_unbalanced(imbalance);
+			Self::deposit_event(Event::AppealDismissed(claim_id, slashed));
+		}
+	}
+}
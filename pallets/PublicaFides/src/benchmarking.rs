@@ -2,35 +2,518 @@
 
 use super::*;
 
-#[allow(unused)]
-use crate::Pallet as Template;
-use frame_benchmarking::{benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use crate::{reputation::Question, Pallet as PublicaFides};
+use codec::Encode;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite};
+use frame_support::{
+	traits::{Currency, EnsureOrigin, Get},
+	weights::Weight,
+};
 use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One, Saturating};
+use sp_std::{vec, vec::Vec};
 
-#[cfg(feature = "runtime-benchmarks")]
-
-// TODO: Implement
-// mod benchmarking {
-// 	use crate::{*, Module as PalletModule};
-// 	use frame_benchmarking::{benchmarks, account, impl_benchmark_test_suite};
-// 	use frame_system::RawOrigin;
-
-// 	benchmarks! {
-// 		store_article {
-// 			let s in 0 .. 100;
-// 			let caller: T::AccountId = whitelisted_caller();
-// 			let x: Vec<u8> = [s, s].to_vec();
-// 			let n: Vec<u8>  = [s, s].to_vec();
-// 		}: _(RawOrigin::Signed(caller), x, n)
-// 		store_claim_for_article {
-// 			let s in 0 .. 100;
-// 			let caller: T::AccountId = whitelisted_caller();
-// 			let x: Vec<u8>  = [s, s].to_vec();
-// 		}: _(RawOrigin::Signed(caller), x, s, false)
-// 		verify {
-// 			assert_eq!(Something::<T>::get(), Some(s));
-// 		}
-// 	}
-// }
+const SEED: u32 = 0;
+
+/// The origin submitting content, funded to pay the deposits.
+fn funded_content_origin<T: Config>() -> Result<T::Origin, &'static str> {
+	let origin = T::ContentOrigin::successful_origin();
+	let who = T::ContentOrigin::ensure_origin(origin.clone())
+		.map_err(|_| "content origin resolves to no account")?;
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	Ok(origin)
+}
+
+/// An account funded to pay the bond of an appeal.
+fn funded_appellant<T: Config>() -> T::AccountId {
+	let appellant: T::AccountId = account("appellant", 0, SEED);
+	T::Currency::make_free_balance_be(&appellant, BalanceOf::<T>::max_value() / 2u32.into());
+	appellant
+}
+
+/// Registers an author and a publisher with the longest possible names.
+fn sources<T: Config>() -> Result<(AuthorId, PublisherId), &'static str> {
+	let name = vec![0; T::MaxCidLength::get() as usize];
+	let author = NextAuthorId::<T>::get();
+	PublicaFides::<T>::register_author(T::SourceOrigin::successful_origin(), name.clone(), vec![])?;
+	let publisher = NextPublisherId::<T>::get();
+	PublicaFides::<T>::register_publisher(T::SourceOrigin::successful_origin(), name, vec![])?;
+	Ok((author, publisher))
+}
+
+/// A url of `length` bytes, unique to the content id as long as it fits.
+fn url<T: Config>(content_id: T::ContentId, length: u32) -> Vec<u8> {
+	// Spelled out in letters, so that normalisation leaves the url alone.
+	let mut url: Vec<u8> = content_id
+		.encode()
+		.into_iter()
+		.flat_map(|byte| vec![b'a' + byte / 16, b'a' + byte % 16])
+		.collect();
+	url.resize(length as usize, b'a');
+	url
+}
+
+/// An archive CID of `length` bytes, unique to the content id as long as it fits.
+fn archive_cid<T: Config>(content_id: T::ContentId, length: u32) -> Vec<u8> {
+	let mut cid = content_id.encode();
+	cid.resize(length as usize, 0);
+	cid
+}
+
+/// The length of the shortest valid DOI used in benchmarks.
+const MIN_DOI_LENGTH: u32 = 9;
+
+/// A valid DOI of `length` bytes, at least `MIN_DOI_LENGTH`.
+fn doi(length: u32) -> Vec<u8> {
+	let mut doi = b"10.1000/".to_vec();
+	doi.resize(length.max(MIN_DOI_LENGTH) as usize, b'a');
+	doi
+}
+
+/// Stores content with the longest possible url, DOI and archive CID, by a registered author and
+/// publisher.
+fn content<T: Config>() -> Result<T::ContentId, &'static str> {
+	let (author, publisher) = sources::<T>()?;
+	let content_id = NextContentId::<T>::get();
+	PublicaFides::<T>::store_content(
+		funded_content_origin::<T>()?,
+		url::<T>(content_id, T::MaxUrlLength::get()),
+		Some(doi(T::MaxDoiLength::get())),
+		Some(author),
+		Some(publisher),
+		Some(archive_cid::<T>(content_id, T::MaxCidLength::get())),
+	)?;
+	Ok(content_id)
+}
+
+/// Stores content in review, full of claims so that scoring it reads as many claims as possible.
+fn full_content<T: Config>() -> Result<T::ContentId, &'static str> {
+	let content_id = content::<T>()?;
+	for _ in 0 .. T::MaxClaimsPerContent::get() {
+		objective_claim::<T>(content_id)?;
+	}
+	Ok(content_id)
+}
+
+/// A claim statement of the longest possible length, unique to the claim id.
+fn claim_text<T: Config>(claim_id: ClaimId) -> Vec<u8> {
+	let mut text = claim_id.to_le_bytes().to_vec();
+	text.resize(T::MaxCidLength::get() as usize, 0);
+	text
+}
+
+/// Stores a new claim for the content.
+fn claim<T: Config>(content_id: T::ContentId) -> Result<ClaimId, &'static str> {
+	let claim_id = NextClaimId::<T>::get();
+	PublicaFides::<T>::store_claim_for_content(
+		T::ClaimOrigin::successful_origin(),
+		claim_text::<T>(claim_id),
+		content_id,
+	)?;
+	Ok(claim_id)
+}
+
+/// Stores a new claim for the content and moves it through the objectivity vote.
+fn objective_claim<T: Config>(content_id: T::ContentId) -> Result<ClaimId, &'static str> {
+	let claim_id = claim::<T>(content_id)?;
+	let origin = T::ObjectivityOrigin::successful_origin();
+	PublicaFides::<T>::approve_claim_objectivity(origin, claim_id)?;
+	Ok(claim_id)
+}
+
+/// Stores a claim raised in `linked` content items, each of which is full of claims, so that
+/// the verdict on the claim rescores as much as possible.
+fn widely_linked_claim<T: Config>(linked: u32, objective: bool) -> Result<ClaimId, &'static str> {
+	let first = content::<T>()?;
+	let claim_id = if objective { objective_claim::<T>(first)? } else { claim::<T>(first)? };
+	for i in 0..linked {
+		let content_id = if i == 0 {
+			first
+		} else {
+			let content_id = content::<T>()?;
+			PublicaFides::<T>::link_claim_to_content(
+				T::ClaimOrigin::successful_origin(),
+				claim_id,
+				content_id,
+			)?;
+			content_id
+		};
+		while (ContentStorage::<T>::get(content_id).claims.len() as u32) <
+			T::MaxClaimsPerContent::get()
+		{
+			objective_claim::<T>(content_id)?;
+		}
+	}
+	Ok(claim_id)
+}
+
+/// Records the votes of `voters` reviewers on the claim, none of whom agree with `aye`, and
+/// funds the reward pot so that the verdict on the claim rewards them.
+fn votes<T: Config>(claim_id: ClaimId, voters: u32, aye: bool) {
+	let nays: Vec<T::AccountId> = (0 .. voters).map(|i| account("voter", i, SEED)).collect();
+	for who in &nays {
+		// Disagreeing reviewers have reputation to lose.
+		ReviewerReputation::<T>::insert(who, Reputation::MAX);
+	}
+	PublicaFides::<T>::record_votes(claim_id, Question::Veracity, aye, &[], &nays);
+	let pot = T::Currency::minimum_balance().saturating_add(T::RewardPerClaim::get());
+	T::Currency::make_free_balance_be(&PublicaFides::<T>::reward_pot(), pot);
+}
+
+benchmarks! {
+	store_content {
+		let u in 1 .. T::MaxUrlLength::get();
+		let d in MIN_DOI_LENGTH .. T::MaxDoiLength::get();
+		let a in 1 .. T::MaxCidLength::get();
+		let (author, publisher) = sources::<T>()?;
+		let origin = funded_content_origin::<T>()?;
+		let content_id = NextContentId::<T>::get();
+		let archive_cid = archive_cid::<T>(content_id, a);
+	}: {
+		PublicaFides::<T>::store_content(
+			origin,
+			url::<T>(content_id, u),
+			Some(doi(d)),
+			Some(author),
+			Some(publisher),
+			Some(archive_cid),
+		)?;
+	}
+	verify {
+		assert!(ContentDeposits::<T>::contains_key(content_id));
+	}
+
+	store_correction {
+		let u in 1 .. T::MaxUrlLength::get();
+		let d in MIN_DOI_LENGTH .. T::MaxDoiLength::get();
+		let a in 1 .. T::MaxCidLength::get();
+		let original = content::<T>()?;
+		let origin = funded_content_origin::<T>()?;
+		let content_id = NextContentId::<T>::get();
+		let archive_cid = archive_cid::<T>(content_id, a);
+	}: {
+		PublicaFides::<T>::store_correction(
+			origin,
+			original,
+			url::<T>(content_id, u),
+			Some(doi(d)),
+			Some(archive_cid),
+		)?;
+	}
+	verify {
+		assert!(ContentDeposits::<T>::contains_key(content_id));
+	}
+
+	complete_review {
+		let content_id = full_content::<T>()?;
+		let origin = T::ReviewOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::complete_review(origin, content_id)?;
+	}
+	verify {
+		assert!(!ContentDeposits::<T>::contains_key(content_id));
+	}
+
+	mark_as_spam {
+		let c in 0 .. T::MaxClaimsPerContent::get();
+		let content_id = content::<T>()?;
+		for _ in 0 .. c {
+			objective_claim::<T>(content_id)?;
+		}
+		let origin = T::ReviewOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::mark_as_spam(origin, content_id)?;
+	}
+	verify {
+		assert_eq!(ContentStorage::<T>::get(content_id).status, ContentStatus::Rejected);
+		// The claims were only raised in the spam.
+		assert!(Claims::<T>::iter().next().is_none());
+	}
+
+	withdraw_content {
+		let content_id = content::<T>()?;
+		let submitter = ContentStorage::<T>::get(content_id).submitter;
+	}: _(RawOrigin::Signed(submitter), content_id)
+	verify {
+		assert_eq!(ContentStorage::<T>::get(content_id).status, ContentStatus::Withdrawn);
+	}
+
+	link_correction {
+		let c in 0 .. T::MaxClaimsPerContent::get();
+		let original = content::<T>()?;
+		for _ in 0 .. c {
+			// Claims with a verdict, so that carrying them over rescores the correction.
+			let claim_id = objective_claim::<T>(original)?;
+			let verdict = T::VeracityOrigin::successful_origin();
+			PublicaFides::<T>::accept_claim_veracity(verdict, claim_id)?;
+		}
+		let correction = content::<T>()?;
+		let origin = T::ClaimOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::link_correction(origin, original, correction)?;
+	}
+	verify {
+		assert_eq!(Corrections::<T>::get(original), Some(correction));
+		assert_eq!(ContentStorage::<T>::get(correction).claims.len() as u32, c);
+	}
+
+	register_author {
+		let n in 1 .. T::MaxCidLength::get();
+		let a in 0 .. T::MaxSourceAccounts::get();
+		let accounts: Vec<T::AccountId> = (0 .. a).map(|i| account("source", i, SEED)).collect();
+		let origin = T::SourceOrigin::successful_origin();
+		let author_id = NextAuthorId::<T>::get();
+	}: {
+		PublicaFides::<T>::register_author(origin, vec![0; n as usize], accounts)?;
+	}
+	verify {
+		assert!(Authors::<T>::contains_key(author_id));
+	}
+
+	register_publisher {
+		let n in 1 .. T::MaxCidLength::get();
+		let a in 0 .. T::MaxSourceAccounts::get();
+		let accounts: Vec<T::AccountId> = (0 .. a).map(|i| account("source", i, SEED)).collect();
+		let origin = T::SourceOrigin::successful_origin();
+		let publisher_id = NextPublisherId::<T>::get();
+	}: {
+		PublicaFides::<T>::register_publisher(origin, vec![0; n as usize], accounts)?;
+	}
+	verify {
+		assert!(Publishers::<T>::contains_key(publisher_id));
+	}
+
+	store_claim_for_content {
+		let s in 1 .. T::MaxCidLength::get();
+		let c in 0 .. T::MaxClaimsPerContent::get() - 1;
+		let content_id = content::<T>()?;
+		for _ in 0 .. c {
+			objective_claim::<T>(content_id)?;
+		}
+		let origin = T::ClaimOrigin::successful_origin();
+		let claim_id = NextClaimId::<T>::get();
+		// Unlike the claims stored above, this one doesn't start with a small claim id.
+		let claim_statement = vec![u8::MAX; s as usize];
+	}: {
+		PublicaFides::<T>::store_claim_for_content(origin, claim_statement, content_id)?;
+	}
+	verify {
+		assert!(Claims::<T>::contains_key(claim_id));
+	}
+
+	link_claim_to_content {
+		let c in 0 .. T::MaxClaimsPerContent::get() - 1;
+		let l in 1 .. T::MaxContentPerClaim::get() - 1;
+		// A claim with a verdict, so that linking it rescores the content.
+		let claim_id = widely_linked_claim::<T>(l, true)?;
+		let verdict = T::VeracityOrigin::successful_origin();
+		PublicaFides::<T>::accept_claim_veracity(verdict, claim_id)?;
+		let content_id = content::<T>()?;
+		for _ in 0 .. c {
+			objective_claim::<T>(content_id)?;
+		}
+		let origin = T::ClaimOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::link_claim_to_content(origin, claim_id, content_id)?;
+	}
+	verify {
+		assert!(ClaimsToContent::<T>::contains_key(claim_id, content_id));
+	}
+
+	approve_claim_objectivity {
+		let content_id = content::<T>()?;
+		let claim_id = claim::<T>(content_id)?;
+		let origin = T::ObjectivityOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::approve_claim_objectivity(origin, claim_id)?;
+	}
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::ObjectivityApproved));
+	}
+
+	reject_claim_as_subjective {
+		let l in 1 .. T::MaxContentPerClaim::get();
+		let v in 0 .. T::MaxVotersPerClaim::get();
+		let claim_id = widely_linked_claim::<T>(l, false)?;
+		votes::<T>(claim_id, v, false);
+		let origin = T::ObjectivityOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::reject_claim_as_subjective(origin, claim_id)?;
+	}
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::RejectedAsSubjective));
+	}
+
+	accept_claim_veracity {
+		let l in 1 .. T::MaxContentPerClaim::get();
+		let v in 0 .. T::MaxVotersPerClaim::get();
+		let claim_id = widely_linked_claim::<T>(l, true)?;
+		votes::<T>(claim_id, v, true);
+		let origin = T::VeracityOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::accept_claim_veracity(origin, claim_id)?;
+	}
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::VeracityAccepted));
+	}
+
+	reject_claim_veracity {
+		let l in 1 .. T::MaxContentPerClaim::get();
+		let v in 0 .. T::MaxVotersPerClaim::get();
+		let claim_id = widely_linked_claim::<T>(l, true)?;
+		votes::<T>(claim_id, v, false);
+		let origin = T::VeracityOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::reject_claim_veracity(origin, claim_id)?;
+	}
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::VeracityRejected));
+	}
+
+	claim_reward {
+		let content_id = content::<T>()?;
+		let claim_id = objective_claim::<T>(content_id)?;
+		// With a second voter, the share has to be computed rather than taking the rest.
+		votes::<T>(claim_id, 2, true);
+		PublicaFides::<T>::accept_claim_veracity(T::VeracityOrigin::successful_origin(), claim_id)?;
+		let caller: T::AccountId = account("voter", 0, SEED);
+	}: _(RawOrigin::Signed(caller.clone()), claim_id)
+	verify {
+		assert!(!RewardShares::<T>::contains_key(claim_id, &caller));
+	}
+
+	appeal_claim {
+		let l in 1 .. T::MaxContentPerClaim::get();
+		let claim_id = widely_linked_claim::<T>(l, true)?;
+		PublicaFides::<T>::accept_claim_veracity(T::VeracityOrigin::successful_origin(), claim_id)?;
+		let caller = funded_appellant::<T>();
+		// Earlier appeals, so that all verdicts in the review are counted.
+		for _ in 1 .. T::MaxVerdicts::get().saturating_sub(1) {
+			PublicaFides::<T>::appeal_claim(RawOrigin::Signed(caller.clone()).into(), claim_id)?;
+			let origin = T::AppealOrigin::successful_origin();
+			PublicaFides::<T>::decide_appeal(origin, claim_id, ClaimStatus::VeracityAccepted)?;
+		}
+	}: _(RawOrigin::Signed(caller), claim_id)
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::UnderAppeal));
+	}
+
+	decide_appeal {
+		let l in 1 .. T::MaxContentPerClaim::get();
+		let claim_id = widely_linked_claim::<T>(l, true)?;
+		PublicaFides::<T>::accept_claim_veracity(T::VeracityOrigin::successful_origin(), claim_id)?;
+		let caller = funded_appellant::<T>();
+		PublicaFides::<T>::appeal_claim(RawOrigin::Signed(caller).into(), claim_id)?;
+		let origin = T::AppealOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::decide_appeal(origin, claim_id, ClaimStatus::VeracityRejected)?;
+	}
+	verify {
+		let status = Claims::<T>::get(claim_id).map(|c| c.status);
+		assert_eq!(status, Some(ClaimStatus::VeracityRejected));
+	}
+
+	set_claim_category {
+		let content_id = content::<T>()?;
+		let claim_id = objective_claim::<T>(content_id)?;
+		// With a final verdict and a review period, the re-review is rescheduled.
+		PublicaFides::<T>::set_review_period(
+			T::ReviewPeriodOrigin::successful_origin(),
+			1,
+			Some(T::BlockNumber::one()),
+		)?;
+		PublicaFides::<T>::accept_claim_veracity(T::VeracityOrigin::successful_origin(), claim_id)?;
+		let origin = T::ClaimOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::set_claim_category(origin, claim_id, 1)?;
+	}
+	verify {
+		assert!(ReviewDue::<T>::contains_key(claim_id));
+	}
+
+	set_review_period {
+		let origin = T::ReviewPeriodOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::set_review_period(origin, 1, Some(T::BlockNumber::one()))?;
+	}
+	verify {
+		assert_eq!(ReviewPeriods::<T>::get(1), Some(T::BlockNumber::one()));
+	}
+
+	approve_public_summary {
+		let content_id = content::<T>()?;
+		let claim_id = objective_claim::<T>(content_id)?;
+		let verdict = T::VeracityOrigin::successful_origin();
+		PublicaFides::<T>::accept_claim_veracity(verdict, claim_id)?;
+		let summary_cid = vec![0; T::MaxCidLength::get() as usize];
+		let origin = T::PublicSummaryOrigin::successful_origin();
+	}: {
+		PublicaFides::<T>::approve_public_summary(origin, claim_id, summary_cid.clone())?;
+	}
+	verify {
+		assert_eq!(PublicaFides::<T>::approved_summary(claim_id), Some(summary_cid));
+	}
+
+	expire_verdicts {
+		let c in 0 .. T::MaxReReviewsPerBlock::get();
+		PublicaFides::<T>::set_review_period(
+			T::ReviewPeriodOrigin::successful_origin(),
+			0,
+			Some(T::BlockNumber::one()),
+		)?;
+		let mut claims = Vec::new();
+		for _ in 0 .. c {
+			let claim_id = widely_linked_claim::<T>(T::MaxContentPerClaim::get(), true)?;
+			let origin = T::VeracityOrigin::successful_origin();
+			PublicaFides::<T>::accept_claim_veracity(origin, claim_id)?;
+			claims.push(claim_id);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		NextReviewBlock::<T>::put(now + T::BlockNumber::one());
+	}: {
+		PublicaFides::<T>::expire_verdicts(now + T::BlockNumber::one());
+	}
+	verify {
+		for claim_id in claims {
+			let status = Claims::<T>::get(claim_id).map(|c| c.status);
+			assert_eq!(status, Some(ClaimStatus::PendingReReview));
+		}
+	}
+
+	close_reviews {
+		let c in 0 .. 10;
+		let contents = (0 .. c).map(|_| full_content::<T>()).collect::<Result<Vec<_>, _>>()?;
+		// All reviews were started in the same block, so they end in the same block.
+		let ends = contents
+			.last()
+			.and_then(|content_id| ReviewEnds::<T>::get(content_id))
+			.unwrap_or_else(frame_system::Pallet::<T>::block_number);
+		NextContentReviewBlock::<T>::put(ends);
+	}: {
+		PublicaFides::<T>::close_ended_reviews(ends, Weight::max_value());
+	}
+	verify {
+		for content_id in contents {
+			assert_eq!(ContentStorage::<T>::get(content_id).status, ContentStatus::Scored);
+		}
+	}
+
+	record_votes {
+		let v in 1 .. T::MaxVotersPerClaim::get();
+		let content_id = content::<T>()?;
+		let claim_id = objective_claim::<T>(content_id)?;
+		let ayes: Vec<T::AccountId> = (0 .. v).map(|i| account("voter", i, SEED)).collect();
+	}: {
+		PublicaFides::<T>::record_votes(claim_id, Question::Veracity, true, &ayes, &[]);
+	}
+	verify {
+		assert_eq!(ClaimVoterCount::<T>::get(claim_id), v);
+	}
+}
 
 impl_benchmark_test_suite!(PublicaFides, crate::mock::new_test_ext(), crate::mock::Test);
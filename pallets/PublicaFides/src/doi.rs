@@ -0,0 +1,27 @@
+//! Syntax of Digital Object Identifiers (DOIs), which identify an article independently of the
+//! url it is served from.
+//!
+//! A DOI is the directory indicator `10`, a registrant code of dot-separated numbers, a `/` and a
+//! suffix chosen by the registrant, e.g. `10.1000/182` or `10.1016.12/j.cell.2019.01.001`. The
+//! suffix can be any printable text. DOIs are stored as given, without a `doi:` or
+//! `https://doi.org/` prefix.
+
+/// Whether `doi` is a syntactically valid DOI.
+pub fn is_valid(doi: &[u8]) -> bool {
+	let separator = match doi.iter().position(|b| *b == b'/') {
+		Some(separator) => separator,
+		None => return false,
+	};
+	let (prefix, suffix) = (&doi[..separator], &doi[separator + 1..]);
+	let mut registrant = match prefix.strip_prefix(b"10.") {
+		Some(registrant) => registrant.split(|b| *b == b'.'),
+		None => return false,
+	};
+	let suffix = match sp_std::str::from_utf8(suffix) {
+		Ok(suffix) => suffix,
+		Err(_) => return false,
+	};
+	registrant.all(|part| !part.is_empty() && part.iter().all(u8::is_ascii_digit)) &&
+		!suffix.is_empty() &&
+		!suffix.chars().any(|c| c.is_whitespace() || c.is_control())
+}
@@ -0,0 +1,136 @@
+//! Fingerprints of content, to detect the same article submitted twice.
+//!
+//! Content is indexed by two fingerprints: the hash of its normalised url in
+//! `ContentByFingerprint`, and the hash of the CID of its archived snapshot, if one was given on
+//! submission, in `ContentByArchive`. Urls are normalised so that the usual variations of a link
+//! to the same article have the same fingerprint: the scheme, a leading `www.`, default ports,
+//! fragments, trailing slashes and tracking parameters are dropped, the host is lowercased and the
+//! remaining query parameters are sorted.
+//!
+//! `store_content` rejects content with `DuplicateContent` if either of its fingerprints is
+//! already indexed, so the same article can't be submitted again under another url or with
+//! another snapshot. A correction submitted with `store_correction` may have the url of the
+//! original, which stays indexed by it, but not its snapshot. Withdrawn content gives up its
+//! fingerprints, so that it can be submitted again, while content rejected as spam keeps them.
+
+use crate::{Config, ContentByArchive, ContentByFingerprint, ContentOf, Pallet};
+use sp_runtime::traits::Hash;
+use sp_std::vec::Vec;
+
+/// Query parameters that only track where readers came from.
+const TRACKING_PARAMETERS: [&[u8]; 7] =
+	[b"fbclid", b"gclid", b"dclid", b"msclkid", b"mc_cid", b"mc_eid", b"igshid"];
+
+/// Whether the query parameter, e.g. `utm_source=feed`, only tracks where readers came from.
+fn is_tracking_parameter(parameter: &[u8]) -> bool {
+	let name = parameter.split(|b| *b == b'=').next().unwrap_or_default().to_ascii_lowercase();
+	name.starts_with(b"utm_") || TRACKING_PARAMETERS.contains(&&name[..])
+}
+
+/// Strips `prefix` from the start of `bytes`, ignoring ASCII case.
+fn strip_prefix_ignore_case<'a>(bytes: &'a [u8], prefix: &[u8]) -> Option<&'a [u8]> {
+	if bytes.len() >= prefix.len() && bytes[..prefix.len()].eq_ignore_ascii_case(prefix) {
+		Some(&bytes[prefix.len()..])
+	} else {
+		None
+	}
+}
+
+/// Normalises a url so that links to the same article are equal, e.g. both
+/// `HTTPS://www.Example.com/news/?utm_source=feed&id=1#top` and `http://example.com/news?id=1`
+/// become `example.com/news?id=1`.
+pub fn normalise_url(url: &[u8]) -> Vec<u8> {
+	let start = url.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(url.len());
+	let end = url.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(start, |end| end + 1);
+	let url = url[start..end].split(|b| *b == b'#').next().unwrap_or_default();
+	let url = strip_prefix_ignore_case(url, b"https://")
+		.or_else(|| strip_prefix_ignore_case(url, b"http://"))
+		.unwrap_or(url);
+
+	let mut parts = url.splitn(2, |b| *b == b'?');
+	let location = parts.next().unwrap_or_default();
+	let query = parts.next().unwrap_or_default();
+	let host_end = location.iter().position(|b| *b == b'/').unwrap_or(location.len());
+	let (host, path) = location.split_at(host_end);
+
+	let host = host.to_ascii_lowercase();
+	let host = strip_prefix_ignore_case(&host, b"www.").unwrap_or(&host);
+	let host = host.strip_suffix(b":443").or_else(|| host.strip_suffix(b":80")).unwrap_or(host);
+	let path_end = path.iter().rposition(|b| *b != b'/').map_or(0, |end| end + 1);
+	let mut parameters: Vec<&[u8]> = query
+		.split(|b| *b == b'&')
+		.filter(|parameter| !parameter.is_empty() && !is_tracking_parameter(parameter))
+		.collect();
+	parameters.sort_unstable();
+
+	let mut normalised = host.to_vec();
+	normalised.extend_from_slice(&path[..path_end]);
+	for (i, parameter) in parameters.into_iter().enumerate() {
+		normalised.push(if i == 0 { b'?' } else { b'&' });
+		normalised.extend_from_slice(parameter);
+	}
+	normalised
+}
+
+impl<T: Config> Pallet<T> {
+	/// The fingerprint of content with the given url.
+	pub fn url_fingerprint(url: &[u8]) -> T::Hash {
+		T::Hashing::hash_of(&normalise_url(url))
+	}
+
+	/// The fingerprint of content with the given archive CID.
+	pub fn archive_fingerprint(archive_cid: &[u8]) -> T::Hash {
+		T::Hashing::hash_of(&archive_cid)
+	}
+
+	/// Looks up existing content with the given url or archive CID.
+	pub fn find_content(url: &[u8], archive_cid: Option<&[u8]>) -> Option<T::ContentId> {
+		ContentByFingerprint::<T>::get(Self::url_fingerprint(url)).or_else(|| {
+			archive_cid.and_then(|cid| ContentByArchive::<T>::get(Self::archive_fingerprint(cid)))
+		})
+	}
+
+	/// Looks up existing content which content with the given url and archive CID would
+	/// duplicate. A correction of `corrects` may have the url of the original.
+	pub(crate) fn find_duplicate(
+		url: &[u8],
+		archive_cid: Option<&[u8]>,
+		corrects: Option<T::ContentId>,
+	) -> Option<T::ContentId> {
+		archive_cid
+			.and_then(|cid| ContentByArchive::<T>::get(Self::archive_fingerprint(cid)))
+			.or_else(|| {
+				ContentByFingerprint::<T>::get(Self::url_fingerprint(url))
+					.filter(|indexed| Some(*indexed) != corrects)
+			})
+	}
+
+	/// Indexes the content by its fingerprints. A url which is already indexed keeps pointing to
+	/// the original content.
+	pub(crate) fn index_fingerprints(
+		content_id: T::ContentId,
+		url: &[u8],
+		archive_cid: Option<&[u8]>,
+	) {
+		ContentByFingerprint::<T>::mutate(Self::url_fingerprint(url), |indexed| {
+			indexed.get_or_insert(content_id);
+		});
+		if let Some(cid) = archive_cid {
+			ContentByArchive::<T>::insert(Self::archive_fingerprint(cid), content_id);
+		}
+	}
+
+	/// Removes the content from the fingerprint indexes, so that it can be submitted again.
+	pub(crate) fn release_fingerprints(content_id: T::ContentId, content: &ContentOf<T>) {
+		let release = |indexed: &mut Option<T::ContentId>| {
+			// Content submitted before fingerprints may share its url with other content.
+			if *indexed == Some(content_id) {
+				*indexed = None;
+			}
+		};
+		ContentByFingerprint::<T>::mutate_exists(Self::url_fingerprint(&content.url), release);
+		if let Some(cid) = &content.archive_cid {
+			ContentByArchive::<T>::mutate_exists(Self::archive_fingerprint(cid), release);
+		}
+	}
+}
@@ -2,6 +2,32 @@
 
 pub use pallet::*;
 
+pub mod scoring;
+pub use scoring::{AcceptedRatio, ClaimTally, Credibility, ScoringPolicy};
+
+pub mod views;
+pub use views::{ClaimInfo, ClaimSummary, ContentInfo, MAX_PAGE_SIZE};
+
+pub mod reputation;
+pub use reputation::ClaimVote;
+
+pub mod rewards;
+pub use rewards::ClaimReward;
+
+pub mod appeals;
+pub use appeals::{Appeal, Verdict};
+
+pub mod reviews;
+
+pub mod lifecycle;
+
+pub mod fingerprint;
+
+pub mod doi;
+
+pub mod summaries;
+pub use summaries::PublicSummary;
+
 #[cfg(test)]
 mod mock;
 
@@ -11,15 +37,38 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod weights;
+pub use weights::WeightInfo;
+
+pub mod migrations;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{
 		dispatch::{DispatchResult, EncodeLike},
 		pallet_prelude::*,
+		traits::{
+			Currency, ExistenceRequirement, Imbalance, OnUnbalanced, ReservableCurrency,
+			StorageVersion,
+		},
+		transactional, PalletId,
 	};
 	use frame_system::pallet_prelude::*;
-	use sp_std::vec::Vec;
-	use sp_runtime::traits::{AtLeast32BitUnsigned, CheckedAdd, One};
+	use sp_std::{convert::TryInto, vec::Vec};
+	use sp_runtime::{
+		traits::{AtLeast32BitUnsigned, CheckedAdd, Hash, One, Saturating, Zero},
+		Perbill, Permill,
+	};
+	use crate::{
+		reputation::ClaimVote,
+		appeals::{Appeal, Verdict},
+		rewards::ClaimReward,
+		scoring::{ClaimTally, Credibility, ScoringPolicy},
+		summaries::PublicSummary,
+		weights::WeightInfo,
+	};
+	#[cfg(feature = "std")]
+	use serde::{Deserialize, Serialize};
 	
 	
 	
@@ -27,31 +76,251 @@ pub mod pallet {
 	pub trait Config: frame_system::Config {
 		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
 		/// Id of content stored in the system
-		type  ContentId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy;
+		type  ContentId: Parameter + Member + AtLeast32BitUnsigned + Default + Copy + MaxEncodedLen;
+		/// Origin allowed to submit content for review. The account it resolves to pays the
+		/// submission deposit.
+		type ContentOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+		/// Origin allowed to record claims for content. This is expected to be a collective
+		/// origin, so that claims are only recorded once a motion has passed.
+		type ClaimOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin deciding whether a proposed claim is an objective statement, e.g. the Panel.
+		type ObjectivityOrigin: EnsureOrigin<Self::Origin>;
+		/// Origin deciding whether an objective claim is true, e.g. the Committee.
+		type VeracityOrigin: EnsureOrigin<Self::Origin>;
+		/// How content is scored from the verdicts on its claims.
+		type ScoringPolicy: ScoringPolicy;
+		/// Origin allowed to register authors and publishers. Sources are registered along with
+		/// the accounts they control, without those accounts consenting, so this is expected to be
+		/// an origin vouching for the sources, e.g. a collective.
+		type SourceOrigin: EnsureOrigin<Self::Origin>;
+		/// Currency in which submission deposits are reserved.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Base deposit reserved when content is submitted.
+		#[pallet::constant]
+		type ContentDepositBase: Get<BalanceOf<Self>>;
+		/// Deposit reserved per byte of the url of submitted content.
+		#[pallet::constant]
+		type ContentDepositPerByte: Get<BalanceOf<Self>>;
+		/// Handler for the deposits slashed from spam submissions.
+		type SpamSlashed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+		/// Origin concluding the review of content, either completing it or marking it as spam.
+		type ReviewOrigin: EnsureOrigin<Self::Origin>;
+		/// Number of blocks content is in review once the first claim is raised in it, after
+		/// which the review is closed and the content scored.
+		#[pallet::constant]
+		type ReviewPeriod: Get<Self::BlockNumber>;
+		/// Maximum length of the url of content.
+		#[pallet::constant]
+		type MaxUrlLength: Get<u32>;
+		/// Maximum length of the DOI of content.
+		#[pallet::constant]
+		type MaxDoiLength: Get<u32>;
+		/// Maximum length of IPFS CIDs, such as claim statements and names of sources.
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+		/// Maximum number of claims linked to one content item.
+		#[pallet::constant]
+		type MaxClaimsPerContent: Get<u32>;
+		/// Maximum number of accounts linked to an author or publisher.
+		#[pallet::constant]
+		type MaxSourceAccounts: Get<u32>;
+		/// Maximum number of content items one claim is linked to.
+		#[pallet::constant]
+		type MaxContentPerClaim: Get<u32>;
+		/// Reputation gained by a reviewer whose votes on a claim are in line with its verdict.
+		#[pallet::constant]
+		type ReputationGain: Get<Reputation>;
+		/// Share of their reputation a reviewer loses when their votes on a claim go against its
+		/// verdict.
+		#[pallet::constant]
+		type ReputationDecay: Get<Perbill>;
+		/// Maximum number of reviewers whose votes on one claim count towards their reputation.
+		#[pallet::constant]
+		type MaxVotersPerClaim: Get<u32>;
+		/// Id of the pallet, from which the account of the reward pot is derived.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+		/// Reward shared by the reviewers who voted on a claim once it is finalized, as far as the
+		/// reward pot allows.
+		#[pallet::constant]
+		type RewardPerClaim: Get<BalanceOf<Self>>;
+		/// Amount minted into the reward pot every block. Zero to fund the pot from slashed
+		/// deposits only.
+		#[pallet::constant]
+		type RewardInflation: Get<BalanceOf<Self>>;
+		/// Origin deciding appeals against the verdicts on claims, e.g. the Board.
+		type AppealOrigin: EnsureOrigin<Self::Origin>;
+		/// Bond reserved from the appellant until an appeal is decided. Slashed to `SpamSlashed`
+		/// if the appeal is dismissed.
+		#[pallet::constant]
+		type AppealBond: Get<BalanceOf<Self>>;
+		/// Maximum number of final verdicts in one review of a claim, counting the first verdict
+		/// and those given on appeal.
+		#[pallet::constant]
+		type MaxVerdicts: Get<u32>;
+		/// Origin setting the review periods of claim categories, e.g. the Board.
+		type ReviewPeriodOrigin: EnsureOrigin<Self::Origin>;
+		/// Maximum number of claims moved to `PendingReReview` per block once their verdicts
+		/// expire. Blocks searched for expired verdicts count towards the limit as well.
+		#[pallet::constant]
+		type MaxReReviewsPerBlock: Get<u32>;
+		/// Origin approving plain-language summaries of claims, e.g. the Council.
+		type PublicSummaryOrigin: EnsureOrigin<Self::Origin>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
 	}
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
 	
 	/// Id of claims made in the system.
-	type ClaimId = u32;
+	pub type ClaimId = u32;
+
+	/// Id of authors of content.
+	pub type AuthorId = u32;
+
+	/// Id of publishers of content.
+	pub type PublisherId = u32;
+
+	/// Reputation of a reviewer.
+	pub type Reputation = u32;
+
+	/// Category of claims, deciding how long the verdicts on them stand.
+	pub type CategoryId = u32;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
-	
-	
 
-	#[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug)]
+	pub type ContentOf<T> = Content<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::MaxUrlLength,
+		<T as Config>::MaxClaimsPerContent,
+		<T as Config>::MaxCidLength,
+		<T as Config>::MaxDoiLength,
+	>;
+	pub type ClaimOf<T> = Claim<<T as Config>::MaxCidLength>;
+	pub type PublicSummaryOf<T> = PublicSummary<<T as Config>::MaxCidLength>;
+	pub type SourceOf<T> = Source<
+		<T as frame_system::Config>::AccountId,
+		<T as Config>::MaxCidLength,
+		<T as Config>::MaxSourceAccounts,
+	>;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
 	/// Represents content in the system.
-	pub struct Content {
+	pub struct Content<
+		AccountId,
+		UrlLimit: Get<u32>,
+		ClaimLimit: Get<u32>,
+		CidLimit: Get<u32>,
+		DoiLimit: Get<u32>,
+	> {
 		/// The URL designated for accessing the Content
-		url: Vec<u8>,
+		pub url: BoundedVec<u8, UrlLimit>,
 		/// u32s representing ids of any Claims raised in the Content
-		claims: Vec<u32>,
+		pub claims: BoundedVec<ClaimId, ClaimLimit>,
+		/// Credibility score computed from the finalized claims. `None` until the
+		/// `ScoringPolicy` can score the content.
+		pub score: Option<Permill>,
+		/// The author of the content, if known.
+		pub author: Option<AuthorId>,
+		/// The publisher of the content, if known.
+		pub publisher: Option<PublisherId>,
+		/// Where the content is in its review.
+		pub status: ContentStatus,
+		/// The account which submitted the content.
+		pub submitter: AccountId,
+		/// The DOI of the content, if it has one.
+		pub doi: Option<BoundedVec<u8, DoiLimit>>,
+		/// The IPFS CID of a snapshot of the content taken on submission, if any. Reviewers judge
+		/// the text in the snapshot, whatever the url serves later.
+		pub archive_cid: Option<BoundedVec<u8, CidLimit>>,
+	}
+
+	impl<
+			AccountId: Default,
+			UrlLimit: Get<u32>,
+			ClaimLimit: Get<u32>,
+			CidLimit: Get<u32>,
+			DoiLimit: Get<u32>,
+		> Default for Content<AccountId, UrlLimit, ClaimLimit, CidLimit, DoiLimit>
+	{
+		fn default() -> Self {
+			Content {
+				url: Default::default(),
+				claims: Default::default(),
+				score: None,
+				author: None,
+				publisher: None,
+				status: Default::default(),
+				submitter: Default::default(),
+				doi: None,
+				archive_cid: None,
+			}
+		}
+	}
+
+	#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// Stage of content in its review.
+	///
+	/// `Submitted -> InReview` once the first claim is raised in the content, then
+	/// `InReview -> Scored` once the review window ends or the review is completed. Content in
+	/// review or not yet in review can also be `Withdrawn` by its submitter or `Rejected` as spam.
+	pub enum ContentStatus {
+		/// The content awaits its first claim.
+		Submitted,
+		/// Claims in the content are being reviewed until the review window ends.
+		InReview,
+		/// The review is closed and the content was scored from the verdicts on its claims.
+		Scored,
+		/// The content was withdrawn by its submitter before its review started.
+		Withdrawn,
+		/// The content was marked as spam.
+		Rejected,
+	}
+
+	impl Default for ContentStatus {
+		fn default() -> Self {
+			ContentStatus::Submitted
+		}
+	}
+
+	impl ContentStatus {
+		/// Whether the review of the content is not concluded yet.
+		pub fn is_open(&self) -> bool {
+			matches!(self, ContentStatus::Submitted | ContentStatus::InReview)
+		}
+	}
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	/// An author or publisher of content.
+	pub struct Source<AccountId, NameLimit: Get<u32>, AccountLimit: Get<u32>> {
+		/// Display name of the source, or the IPFS CID of it.
+		pub name: BoundedVec<u8, NameLimit>,
+		/// Accounts controlled by the source, if any.
+		pub accounts: BoundedVec<AccountId, AccountLimit>,
 	}
 
 	#[pallet::storage]
 	#[pallet::getter(fn get_content)]
 	pub type ContentStorage<T: Config> =
-		StorageMap<_, Blake2_128Concat, T::ContentId, Content, ValueQuery>;
+		StorageMap<_, Blake2_128Concat, T::ContentId, ContentOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn content_deposit)]
+	/// Deposits reserved for content that is still under review, with the account that paid them.
+	pub type ContentDeposits<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ContentId, (T::AccountId, BalanceOf<T>)>;
 
 	/// Next available class ID.
 	#[pallet::storage]
@@ -62,90 +331,716 @@ pub mod pallet {
 	#[pallet::getter(fn next_claim_id)]
 	pub type NextClaimId<T: Config> = StorageValue<_, ClaimId, ValueQuery>;
 
-	#[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug)]
-	/// Claims made in scientific articles. Proposers can introduce claims as accepted or rejected to reflect the veracity of the content.
-	pub struct Claim {
+	#[pallet::storage]
+	#[pallet::getter(fn author)]
+	/// Registered authors of content.
+	pub type Authors<T: Config> = StorageMap<_, Blake2_128Concat, AuthorId, SourceOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_author_id)]
+	pub type NextAuthorId<T: Config> = StorageValue<_, AuthorId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn author_credibility)]
+	/// Credibility of authors, aggregated from the scores of their content.
+	pub type AuthorCredibility<T: Config> =
+		StorageMap<_, Blake2_128Concat, AuthorId, Credibility, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn publisher)]
+	/// Registered publishers of content.
+	pub type Publishers<T: Config> = StorageMap<_, Blake2_128Concat, PublisherId, SourceOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_publisher_id)]
+	pub type NextPublisherId<T: Config> = StorageValue<_, PublisherId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn publisher_credibility)]
+	/// Credibility of publishers, aggregated from the scores of their content.
+	pub type PublisherCredibility<T: Config> =
+		StorageMap<_, Blake2_128Concat, PublisherId, Credibility, ValueQuery>;
+
+	#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+	/// Stage of a claim in the review process.
+	///
+	/// Claims are first voted on for objectivity, and only objective claims are voted on for
+	/// veracity: `Proposed -> ObjectivityApproved | RejectedAsSubjective`, then
+	/// `ObjectivityApproved -> VeracityAccepted | VeracityRejected`. Final verdicts can be
+	/// appealed, which moves the claim to `UnderAppeal` until it is given a final status again.
+	/// Once a verdict expires, the claim is `PendingReReview` and voted on like a proposed claim.
+	pub enum ClaimStatus {
+		/// The claim was raised and awaits the objectivity vote.
+		Proposed,
+		/// The claim is an objective statement and awaits the veracity vote.
+		ObjectivityApproved,
+		/// The claim is a subjective statement. Final.
+		RejectedAsSubjective,
+		/// The claim is accepted as true. Final.
+		VeracityAccepted,
+		/// The claim is rejected as false. Final.
+		VeracityRejected,
+		/// The final verdict on the claim was appealed and awaits the decision on the appeal.
+		UnderAppeal,
+		/// The verdict on the claim expired and the claim awaits the objectivity vote again.
+		PendingReReview,
+	}
+
+	impl Default for ClaimStatus {
+		fn default() -> Self {
+			ClaimStatus::Proposed
+		}
+	}
+
+	impl ClaimStatus {
+		/// Whether the claim has received its final verdict.
+		pub fn is_final(&self) -> bool {
+			matches!(
+				self,
+				ClaimStatus::RejectedAsSubjective |
+					ClaimStatus::VeracityAccepted |
+					ClaimStatus::VeracityRejected
+			)
+		}
+	}
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	/// Claims made in scientific articles. Collectives vote on their objectivity and then on their veracity.
+	pub struct Claim<CidLimit: Get<u32>> {
 		/// the IPFS CID of the text that contains the objective claim statement.
-		pub claim_text_cid: Vec<u8>,
-		/// Whether the claim is determined to be accepted or rejected by the Collective instance. Using an affirmative for wider understanding..
-		pub is_accepted: bool,
+		pub claim_text_cid: BoundedVec<u8, CidLimit>,
+		/// Where the claim currently is in the objectivity/veracity review.
+		pub status: ClaimStatus,
 	}
 
 	#[pallet::storage]
-	#[pallet::getter(fn get_claims)]
-	/// Double Storage map that maps claims to the articles they originated from
+	#[pallet::getter(fn get_claim)]
+	/// Claims by id. A claim can be raised in any number of content items.
+	pub type Claims<T: Config> = StorageMap<_, Blake2_128Concat, ClaimId, ClaimOf<T>>;
+
+	#[pallet::storage]
+	/// Links between claims and the content they were discovered in. The reverse index from content
+	/// to claims is `Content::claims`.
 	pub type ClaimsToContent<T: Config> = StorageDoubleMap<
 		_,
 		Blake2_128Concat,
 		ClaimId,
 		Blake2_128Concat,
 		T::ContentId,
-		Claim,
-		ValueQuery,
+		(),
+	>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claim_by_text_hash)]
+	/// Claims by the hash of their `claim_text_cid`, used to point proposers to an existing claim
+	/// instead of creating a duplicate.
+	pub type ClaimByTextHash<T: Config> = StorageMap<_, Identity, T::Hash, ClaimId>;
+
+	#[pallet::storage]
+	/// Votes of reviewers on claims which haven't received their final verdict yet.
+	pub type ClaimVotes<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClaimId,
+		Blake2_128Concat,
+		T::AccountId,
+		ClaimVote,
+	>;
+
+	#[pallet::storage]
+	/// Number of reviewers with votes in `ClaimVotes`, by claim.
+	pub type ClaimVoterCount<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reputation)]
+	/// Reputation of reviewers, from how their votes compare to the final verdicts on claims.
+	pub type ReviewerReputation<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Reputation, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claim_reward_of)]
+	/// Rewards of finalized claims which weren't fully paid out yet.
+	pub type ClaimRewards<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimId, ClaimReward<BalanceOf<T>>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn reward_share)]
+	/// Shares of reviewers in the rewards of claims, which they didn't claim yet.
+	pub type RewardShares<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		ClaimId,
+		Blake2_128Concat,
+		T::AccountId,
+		Reputation,
+	>;
+
+	#[pallet::storage]
+	/// Sum of the rewards in `ClaimRewards`, which the reward pot holds on to.
+	pub type AllocatedRewards<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn appeal)]
+	/// Appeals against the verdicts on claims which weren't decided yet.
+	pub type Appeals<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimId, Appeal<T::AccountId, BalanceOf<T>>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn verdict)]
+	/// The final verdicts on claims, by the order in which they were given. Verdicts are never
+	/// removed, see `verdict_history`.
+	pub type VerdictHistory<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, ClaimId, Twox64Concat, u32, Verdict<T::BlockNumber>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn verdict_count)]
+	/// The number of final verdicts given on claims.
+	pub type VerdictCount<T: Config> = StorageMap<_, Blake2_128Concat, ClaimId, u32, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn content_by_fingerprint)]
+	/// Content by the fingerprint of its url, see `url_fingerprint`.
+	pub type ContentByFingerprint<T: Config> = StorageMap<_, Identity, T::Hash, T::ContentId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn content_by_archive)]
+	/// Content by the fingerprint of its archive CID, see `archive_fingerprint`.
+	pub type ContentByArchive<T: Config> = StorageMap<_, Identity, T::Hash, T::ContentId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn correction_of)]
+	/// Corrected versions of content, by the id of the original content.
+	pub type Corrections<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::ContentId, T::ContentId>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn review_ends)]
+	/// The block in which the review window of content in review ends.
+	pub type ReviewEnds<T: Config> = StorageMap<_, Blake2_128Concat, T::ContentId, T::BlockNumber>;
+
+	#[pallet::storage]
+	/// Content in review by the block in which its review window ends.
+	pub type ContentReviewQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		T::ContentId,
+		(),
 	>;
 
+	#[pallet::storage]
+	/// The first block of `ContentReviewQueue` which may still hold reviews to close.
+	pub type NextContentReviewBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn public_summary)]
+	/// Plain-language summaries of claims, see `summaries`.
+	pub type PublicSummaries<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimId, PublicSummaryOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn claim_category)]
+	/// Categories of claims. Claims are in category 0 unless set otherwise.
+	pub type ClaimCategories<T: Config> =
+		StorageMap<_, Blake2_128Concat, ClaimId, CategoryId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn review_period)]
+	/// How long the verdicts on claims of a category stand before the claims are reviewed again.
+	/// Verdicts on claims of categories without a review period stand for good.
+	pub type ReviewPeriods<T: Config> = StorageMap<_, Twox64Concat, CategoryId, T::BlockNumber>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn review_due)]
+	/// The block in which the verdict on a claim expires, for claims with a standing verdict in a
+	/// category with a review period.
+	pub type ReviewDue<T: Config> = StorageMap<_, Blake2_128Concat, ClaimId, T::BlockNumber>;
+
+	#[pallet::storage]
+	/// Claims by the block in which their verdicts expire.
+	pub type ReviewQueue<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		ClaimId,
+		(),
+	>;
+
+	#[pallet::storage]
+	/// The first block of `ReviewQueue` which may still hold claims with expired verdicts.
+	pub type NextReviewBlock<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
 	#[pallet::event]
-	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::metadata(
+		T::AccountId = "AccountId",
+		T::BlockNumber = "BlockNumber",
+		BalanceOf<T> = "Balance"
+	)]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
 		ContentStored(T::ContentId),
 		ClaimStored(ClaimId),
+		/// A claim was judged to be an objective statement. \[claim_id\]
+		ClaimObjectivityApproved(ClaimId),
+		/// A claim was judged to be a subjective statement. \[claim_id\]
+		ClaimRejectedAsSubjective(ClaimId),
+		/// An objective claim was accepted as true. \[claim_id\]
+		ClaimVeracityAccepted(ClaimId),
+		/// An objective claim was rejected as false. \[claim_id\]
+		ClaimVeracityRejected(ClaimId),
+		/// An existing claim was linked to more content. \[claim_id, content_id\]
+		ClaimLinked(ClaimId, T::ContentId),
+		/// A claim was removed as it is no longer raised in any content. \[claim_id\]
+		ClaimRemoved(ClaimId),
+		/// The credibility score of content changed. \[content_id, score\]
+		ContentScoreUpdated(T::ContentId, Permill),
+		/// An author was registered. \[author_id\]
+		AuthorRegistered(AuthorId),
+		/// A publisher was registered. \[publisher_id\]
+		PublisherRegistered(PublisherId),
+		/// The review of content was closed, the content scored and its deposit returned.
+		/// \[content_id\]
+		ContentReviewCompleted(T::ContentId),
+		/// Content was marked as spam and rejected, and its deposit slashed.
+		/// \[content_id, slashed\]
+		ContentMarkedAsSpam(T::ContentId, BalanceOf<T>),
+		/// The reputation of the reviewers who voted on a claim was updated to its verdict.
+		/// \[claim_id, voters\]
+		ReputationSettled(ClaimId, u32),
+		/// A reward was set aside for the reviewers who voted on a claim. \[claim_id, reward\]
+		RewardsAllocated(ClaimId, BalanceOf<T>),
+		/// A reviewer was paid their share in the reward of a claim.
+		/// \[claim_id, reviewer, amount\]
+		RewardPaid(ClaimId, T::AccountId, BalanceOf<T>),
+		/// The verdict on a claim was appealed. \[claim_id, appellant\]
+		ClaimAppealed(ClaimId, T::AccountId),
+		/// An appeal gave a claim a new verdict, and the bond was returned.
+		/// \[claim_id, status\]
+		AppealUpheld(ClaimId, ClaimStatus),
+		/// An appeal confirmed the verdict on a claim, and the bond was slashed.
+		/// \[claim_id, slashed\]
+		AppealDismissed(ClaimId, BalanceOf<T>),
+		/// A claim was moved to a category. \[claim_id, category\]
+		ClaimCategorized(ClaimId, CategoryId),
+		/// The review period of a category was set, or removed if `None`.
+		/// \[category, review_period\]
+		ReviewPeriodSet(CategoryId, Option<T::BlockNumber>),
+		/// The verdict on a claim expired and the claim awaits new motions to review it again.
+		/// \[claim_id, expired_status\]
+		ClaimPendingReReview(ClaimId, ClaimStatus),
+		/// A plain-language summary of a claim was approved for the verdict on the claim.
+		/// \[claim_id, verdict\]
+		PublicSummaryApproved(ClaimId, ClaimStatus),
+		/// The first claim was raised in content, which is in review until the given block.
+		/// \[content_id, review_ends\]
+		ContentReviewStarted(T::ContentId, T::BlockNumber),
+		/// Content was withdrawn by its submitter and its deposit returned. \[content_id\]
+		ContentWithdrawn(T::ContentId),
+		/// Content was linked to its corrected version, and its claims carried over.
+		/// \[original, correction, carried_claims\]
+		ContentCorrected(T::ContentId, T::ContentId, u32),
 	}
 
 	#[pallet::error]
 	pub enum Error<T> {
 		NoAvailableContentId,
 		NoAvailableClaimId,
-		NonExistentContent
+		NonExistentContent,
+		/// No claim with the given id exists.
+		NonExistentClaim,
+		/// The claim is not in the status required by this transition.
+		InvalidClaimTransition,
+		/// A claim with the same text already exists. Link the existing claim to the content
+		/// instead, see `claim_by_text_hash`.
+		DuplicateClaim,
+		/// The claim is already linked to the content.
+		ClaimAlreadyLinked,
+		NoAvailableAuthorId,
+		NoAvailablePublisherId,
+		/// No author with the given id exists.
+		NonExistentAuthor,
+		/// No publisher with the given id exists.
+		NonExistentPublisher,
+		/// The review of the content was already concluded.
+		ReviewConcluded,
+		/// The url is longer than `MaxUrlLength`.
+		UrlTooLong,
+		/// The DOI is longer than `MaxDoiLength`.
+		DoiTooLong,
+		/// The CID or name is longer than `MaxCidLength`.
+		CidTooLong,
+		/// The content already has `MaxClaimsPerContent` claims.
+		TooManyClaims,
+		/// More than `MaxSourceAccounts` accounts were given for an author or publisher.
+		TooManySourceAccounts,
+		/// The claim is already linked to `MaxContentPerClaim` content items.
+		TooManyContentLinks,
+		/// The account has no reward to claim for the claim.
+		NoReward,
+		/// The claim already has `MaxVerdicts` verdicts in its current review and can't be
+		/// appealed again.
+		TooManyAppeals,
+		/// Appeals can only be decided with a final status.
+		InvalidVerdict,
+		/// Review periods must be at least one block.
+		ZeroReviewPeriod,
+		/// Only the submitter of content can withdraw it.
+		NotSubmitter,
+		/// Content can only be withdrawn before its review started.
+		ReviewStarted,
+		/// The content already has a corrected version.
+		AlreadyCorrected,
+		/// Content can't be its own correction, and withdrawn or rejected content can't be
+		/// corrected.
+		InvalidCorrection,
+		/// The same content was already submitted, see `find_content`.
+		DuplicateContent,
+		/// The DOI is not syntactically valid.
+		InvalidDoi,
+		/// Only claims with a final verdict can be summarised.
+		NoFinalVerdict,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let weight = Self::expire_verdicts(n);
+			let inflation = T::RewardInflation::get();
+			if inflation.is_zero() {
+				return weight
+			}
+			drop(T::Currency::deposit_creating(&Self::reward_pot(), inflation));
+			weight.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		}
+
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::close_ended_reviews(n, remaining_weight)
+		}
+
+		fn on_runtime_upgrade() -> Weight {
+			crate::migrations::v1::migrate::<T>()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
+		#[pallet::weight(T::WeightInfo::store_content(
+			url.len() as u32,
+			doi.as_ref().map_or(0, |doi| doi.len() as u32),
+			archive_cid.as_ref().map_or(0, |cid| cid.len() as u32),
+		))]
 		/// Stores an article in the system to initiate the claims-voting process
 		///
+		/// Reserves `ContentDepositBase` plus `ContentDepositPerByte` for each byte of `url` from
+		/// the submitter until the review is concluded.
+		///
+		/// Fails with `DuplicateContent` if the same article was already submitted, see
+		/// `fingerprint` for what counts as the same article.
+		///
 		/// # Arguments
 		///
 		/// * `origin` - Origin of the request.
 		/// * `url` - Url of the article. Displayed for the purpose of allowing voters to find and read the content.
+		/// * `doi` - DOI of the article, if it has one, e.g. `10.1000/182`.
+		/// * `author` - Id of the registered author of the article, if known.
+		/// * `publisher` - Id of the registered publisher of the article, if known.
+		/// * `archive_cid` - IPFS CID of a snapshot of the article as published, if archived.
+		#[transactional]
 		pub fn store_content(
 			origin: OriginFor<T>,
 			url: Vec<u8>,
+			doi: Option<Vec<u8>>,
+			author: Option<AuthorId>,
+			publisher: Option<PublisherId>,
+			archive_cid: Option<Vec<u8>>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
-			let class_id =
-				NextContentId::<T>::try_mutate(|id| -> Result<T::ContentId, DispatchError> {
+			let who = T::ContentOrigin::ensure_origin(origin)?;
+			Self::insert_content(who, url, doi, author, publisher, archive_cid, None)
+		}
+
+		#[pallet::weight(T::WeightInfo::store_correction(
+			url.len() as u32,
+			doi.as_ref().map_or(0, |doi| doi.len() as u32),
+			archive_cid.as_ref().map_or(0, |cid| cid.len() as u32),
+		))]
+		/// Stores the corrected version of content, e.g. after the publisher issued a correction,
+		/// by the author and publisher of the original. Unlike `store_content`, the correction
+		/// may have the url of the original, as publishers often correct articles in place, but
+		/// not its archived snapshot. The Panel then links it with `link_correction`.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request.
+		/// * `original` - Id of the original content.
+		/// * `url` - Url of the corrected article.
+		/// * `doi` - DOI of the corrected article, if it has one.
+		/// * `archive_cid` - IPFS CID of a snapshot of the corrected article, if archived.
+		#[transactional]
+		pub fn store_correction(
+			origin: OriginFor<T>,
+			original: T::ContentId,
+			url: Vec<u8>,
+			doi: Option<Vec<u8>>,
+			archive_cid: Option<Vec<u8>>,
+		) -> DispatchResult {
+			let who = T::ContentOrigin::ensure_origin(origin)?;
+			let original_content =
+				ContentStorage::<T>::try_get(original).map_err(|_| Error::<T>::NonExistentContent)?;
+			ensure!(
+				!matches!(
+					original_content.status,
+					ContentStatus::Withdrawn | ContentStatus::Rejected
+				),
+				Error::<T>::InvalidCorrection
+			);
+			ensure!(!Corrections::<T>::contains_key(original), Error::<T>::AlreadyCorrected);
+			Self::insert_content(
+				who,
+				url,
+				doi,
+				original_content.author,
+				original_content.publisher,
+				archive_cid,
+				Some(original),
+			)
+		}
+
+		#[pallet::weight(T::WeightInfo::complete_review())]
+		/// Concludes the review of content before its review window ends. The content is scored
+		/// and the deposit returned to the submitter.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of a collective.
+		/// * `content_id` - Id of the reviewed content.
+		pub fn complete_review(origin: OriginFor<T>, content_id: T::ContentId) -> DispatchResult {
+			T::ReviewOrigin::ensure_origin(origin)?;
+			ensure!(ContentStorage::<T>::contains_key(content_id), Error::<T>::NonExistentContent);
+			Self::close_review(content_id)
+		}
+
+		#[pallet::weight(T::WeightInfo::mark_as_spam(T::MaxClaimsPerContent::get()))]
+		/// Marks content as spam. The content is `Rejected` and unlinked from its claims, and the
+		/// deposit of the submitter is slashed to `SpamSlashed`. Claims which aren't raised in
+		/// any other content are removed.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of a collective.
+		/// * `content_id` - Id of the spam content.
+		pub fn mark_as_spam(
+			origin: OriginFor<T>,
+			content_id: T::ContentId,
+		) -> DispatchResultWithPostInfo {
+			T::ReviewOrigin::ensure_origin(origin)?;
+			ensure!(ContentStorage::<T>::contains_key(content_id), Error::<T>::NonExistentContent);
+			let content = ContentStorage::<T>::try_mutate(
+				content_id,
+				|content| -> Result<ContentOf<T>, DispatchError> {
+					ensure!(content.status.is_open(), Error::<T>::ReviewConcluded);
+					let rejected = content.clone();
+					content.status = ContentStatus::Rejected;
+					content.claims = Default::default();
+					content.score = None;
+					Ok(rejected)
+				},
+			)?;
+			Self::cancel_review_window(content_id);
+			let mut slashed = Zero::zero();
+			if let Some((who, deposit)) = ContentDeposits::<T>::take(content_id) {
+				let (imbalance, _) = T::Currency::slash_reserved(&who, deposit);
+				slashed = imbalance.peek();
+				T::SpamSlashed::on_unbalanced(imbalance);
+			}
+
+			for claim_id in content.claims.iter() {
+				Self::unlink_claim(*claim_id, content_id);
+			}
+			if let Some(author) = content.author {
+				AuthorCredibility::<T>::mutate(author, |c| c.rescore(content.score, None));
+			}
+			if let Some(publisher) = content.publisher {
+				PublisherCredibility::<T>::mutate(publisher, |c| c.rescore(content.score, None));
+			}
+
+			Self::deposit_event(Event::ContentMarkedAsSpam(content_id, slashed));
+			Ok(Some(T::WeightInfo::mark_as_spam(content.claims.len() as u32)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw_content())]
+		/// Withdraws submitted content before the first claim is raised in it, and returns the
+		/// deposit. The content can be submitted again later.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The submitter of the content.
+		/// * `content_id` - Id of the content.
+		pub fn withdraw_content(origin: OriginFor<T>, content_id: T::ContentId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(ContentStorage::<T>::contains_key(content_id), Error::<T>::NonExistentContent);
+			let content = ContentStorage::<T>::try_mutate(
+				content_id,
+				|content| -> Result<_, DispatchError> {
+					ensure!(content.submitter == who, Error::<T>::NotSubmitter);
+					ensure!(content.status.is_open(), Error::<T>::ReviewConcluded);
+					ensure!(content.status == ContentStatus::Submitted, Error::<T>::ReviewStarted);
+					content.status = ContentStatus::Withdrawn;
+					Ok(content.clone())
+				},
+			)?;
+			if let Some((who, deposit)) = ContentDeposits::<T>::take(content_id) {
+				T::Currency::unreserve(&who, deposit);
+			}
+			Self::release_fingerprints(content_id, &content);
+
+			Self::deposit_event(Event::ContentWithdrawn(content_id));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::link_correction(T::MaxClaimsPerContent::get()))]
+		/// Records `correction` as the corrected version of the `original` content, e.g. after
+		/// the publisher issued a correction. The claims of the original are carried over to the
+		/// correction, where the claims with a verdict count towards the score right away. Claims
+		/// the correction no longer makes are re-evaluated through the usual motions, while new
+		/// claims can be raised in the correction until its review window ends.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the Panel.
+		/// * `original` - Id of the original content.
+		/// * `correction` - Id of the corrected version, which must still be open for review.
+		#[transactional]
+		pub fn link_correction(
+			origin: OriginFor<T>,
+			original: T::ContentId,
+			correction: T::ContentId,
+		) -> DispatchResultWithPostInfo {
+			T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(original != correction, Error::<T>::InvalidCorrection);
+			let original_content =
+				ContentStorage::<T>::try_get(original).map_err(|_| Error::<T>::NonExistentContent)?;
+			let correction_status = ContentStorage::<T>::try_get(correction)
+				.map_err(|_| Error::<T>::NonExistentContent)?
+				.status;
+			ensure!(
+				!matches!(
+					original_content.status,
+					ContentStatus::Withdrawn | ContentStatus::Rejected
+				),
+				Error::<T>::InvalidCorrection
+			);
+			ensure!(correction_status.is_open(), Error::<T>::ReviewConcluded);
+			ensure!(!Corrections::<T>::contains_key(original), Error::<T>::AlreadyCorrected);
+
+			let mut carried = 0;
+			let mut rescore = false;
+			for claim_id in original_content.claims.iter() {
+				if ClaimsToContent::<T>::contains_key(claim_id, correction) {
+					continue
+				}
+				Self::link_claim(*claim_id, correction)?;
+				carried += 1;
+				rescore |= Self::get_claim(claim_id).map_or(false, |claim| claim.status.is_final());
+			}
+			if rescore {
+				Self::update_score(correction);
+			}
+			Corrections::<T>::insert(original, correction);
+
+			Self::deposit_event(Event::ContentCorrected(original, correction, carried));
+			Ok(Some(T::WeightInfo::link_correction(original_content.claims.len() as u32)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::register_author(name.len() as u32, accounts.len() as u32))]
+		/// Registers an author of content.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request.
+		/// * `name` - Display name of the author, or the IPFS CID of it.
+		/// * `accounts` - Accounts controlled by the author, if any.
+		pub fn register_author(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::SourceOrigin::ensure_origin(origin)?;
+			let source = Self::source(name, accounts)?;
+			let author_id = NextAuthorId::<T>::try_mutate(|id| -> Result<AuthorId, DispatchError> {
+				let current_id = *id;
+				*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailableAuthorId)?;
+				Ok(current_id)
+			})?;
+
+			Authors::<T>::insert(author_id, source);
+			Self::deposit_event(Event::AuthorRegistered(author_id));
+			Ok(())
+		}
+
+		#[pallet::weight(
+			T::WeightInfo::register_publisher(name.len() as u32, accounts.len() as u32)
+		)]
+		/// Registers a publisher of content.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request.
+		/// * `name` - Display name of the publisher, or the IPFS CID of it.
+		/// * `accounts` - Accounts controlled by the publisher, if any.
+		pub fn register_publisher(
+			origin: OriginFor<T>,
+			name: Vec<u8>,
+			accounts: Vec<T::AccountId>,
+		) -> DispatchResult {
+			T::SourceOrigin::ensure_origin(origin)?;
+			let source = Self::source(name, accounts)?;
+			let publisher_id =
+				NextPublisherId::<T>::try_mutate(|id| -> Result<PublisherId, DispatchError> {
 					let current_id = *id;
-					*id = id.checked_add(&One::one()).ok_or(Error::<T>::NoAvailableContentId)?;
+					*id = id.checked_add(One::one()).ok_or(Error::<T>::NoAvailablePublisherId)?;
 					Ok(current_id)
 				})?;
 
-			let content = Content { url, claims : [].to_vec() };
-			ContentStorage::<T>::insert(class_id.clone(), content);
-			Self::deposit_event(Event::ContentStored(class_id));
-			// Return a successful DispatchResultWithPostInfo
+			Publishers::<T>::insert(publisher_id, source);
+			Self::deposit_event(Event::PublisherRegistered(publisher_id));
 			Ok(())
 		}
 
-		#[pallet::weight(10_000 + T::DbWeight::get().writes(1))]
-		/// Stores a claim for given content. This should be called by the collective propose extrinsic.
+		#[pallet::weight(T::WeightInfo::store_claim_for_content(
+			claim_statement.len() as u32,
+			T::MaxClaimsPerContent::get(),
+		))]
+		/// Stores a claim for given content. This should be dispatched by a passed collective motion.
+		///
+		/// The claim starts out as `Proposed` and awaits the objectivity vote. Claims are
+		/// deduplicated by their text: if the same claim was already raised, link the existing claim
+		/// with `link_claim_to_content` instead.
 		///
 		/// # Arguments
 		///
 		/// * `origin` - Origin of the request
 		/// * `claim_statement` - IPFS CID of a stored string that contains an objective claim. This claim will be voted on for veracity.
 		/// * `content_id` - Id of the content the claim was discovered in.
-		/// * `is_accepted` - Designates whether the claim is accepted, or rejected, by the council.
+		#[transactional]
 		pub fn store_claim_for_content(
 			origin: OriginFor<T>,
 			claim_statement: Vec<u8>,
 			content_id: T::ContentId,
-			is_accepted: bool,
 		) -> DispatchResult {
-			// TODO: Find way to ensure this was called by the `propose` extrinsic. This fails with BadOrigin when called by the `propose` pallet.
-			ensure_signed(origin)?;
+			T::ClaimOrigin::ensure_origin(origin)?;
 			// Ensure that the article exists
 			ensure!(ContentStorage::<T>::contains_key(content_id), Error::<T>::NonExistentContent);
+			let text_hash = T::Hashing::hash(&claim_statement);
+			let claim_text_cid: BoundedVec<u8, T::MaxCidLength> =
+				claim_statement.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+			ensure!(
+				!ClaimByTextHash::<T>::contains_key(text_hash),
+				Error::<T>::DuplicateClaim
+			);
 
 			let new_claim_id =
 				NextClaimId::<T>::try_mutate(|claim_id| -> Result<ClaimId, DispatchError> {
@@ -155,22 +1050,511 @@ pub mod pallet {
 					Ok(current_id)
 				})?;
 
-			ClaimsToContent::<T>::insert(
-				new_claim_id,
-				content_id.clone(),
-                Claim { claim_text_cid: claim_statement, is_accepted },
+			Self::link_claim(new_claim_id, content_id)?;
+			Claims::<T>::insert(new_claim_id, Claim { claim_text_cid, status: ClaimStatus::Proposed });
+			ClaimByTextHash::<T>::insert(text_hash, new_claim_id);
+
+			Self::deposit_event(Event::ClaimStored(new_claim_id));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::link_claim_to_content(
+			T::MaxClaimsPerContent::get(),
+			T::MaxContentPerClaim::get(),
+		))]
+		/// Links an existing claim to more content it was discovered in.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request
+		/// * `claim_id` - Id of the existing claim.
+		/// * `content_id` - Id of the content the claim was also discovered in.
+		pub fn link_claim_to_content(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+			content_id: T::ContentId,
+		) -> DispatchResult {
+			T::ClaimOrigin::ensure_origin(origin)?;
+			ensure!(ContentStorage::<T>::contains_key(content_id), Error::<T>::NonExistentContent);
+			let claim = Claims::<T>::get(claim_id).ok_or(Error::<T>::NonExistentClaim)?;
+			ensure!(
+				!ClaimsToContent::<T>::contains_key(claim_id, content_id),
+				Error::<T>::ClaimAlreadyLinked
 			);
 
-			ContentStorage::<T>::try_mutate_exists(content_id.clone(), |val| -> DispatchResult {
-				// add claim id to content for future reference
-				let content = val.as_mut().ok_or(Error::<T>::NonExistentContent).unwrap();
-				content.claims.push(new_claim_id);
-				Self::deposit_event(Event::ClaimStored(new_claim_id));
-				Ok(())
-			});
+			Self::link_claim(claim_id, content_id)?;
+			// A claim which already has a verdict counts towards the score right away.
+			if claim.status.is_final() {
+				Self::update_score(content_id);
+			}
 
-			Self::deposit_event(Event::ClaimStored(new_claim_id));
+			Self::deposit_event(Event::ClaimLinked(claim_id, content_id));
 			Ok(())
 		}
+
+		#[pallet::weight(T::WeightInfo::approve_claim_objectivity())]
+		/// Marks a proposed claim, or a claim pending re-review, as an objective statement, so that
+		/// it can be voted on for veracity.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the objectivity collective.
+		/// * `claim_id` - Id of the claim.
+		pub fn approve_claim_objectivity(origin: OriginFor<T>, claim_id: ClaimId) -> DispatchResult {
+			T::ObjectivityOrigin::ensure_origin(origin)?;
+			Self::transition_claim(
+				claim_id,
+				&[ClaimStatus::Proposed, ClaimStatus::PendingReReview],
+				ClaimStatus::ObjectivityApproved,
+			)?;
+			Self::deposit_event(Event::ClaimObjectivityApproved(claim_id));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::reject_claim_as_subjective(
+			T::MaxContentPerClaim::get(),
+			T::MaxVotersPerClaim::get(),
+		))]
+		/// Rejects a proposed claim, or a claim pending re-review, as a subjective statement. The
+		/// claim will not be voted on for veracity.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the objectivity collective.
+		/// * `claim_id` - Id of the claim.
+		pub fn reject_claim_as_subjective(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+		) -> DispatchResultWithPostInfo {
+			T::ObjectivityOrigin::ensure_origin(origin)?;
+			let (rescored, voters) = Self::transition_claim(
+				claim_id,
+				&[ClaimStatus::Proposed, ClaimStatus::PendingReReview],
+				ClaimStatus::RejectedAsSubjective,
+			)?;
+			Self::deposit_event(Event::ClaimRejectedAsSubjective(claim_id));
+			Ok(Some(T::WeightInfo::reject_claim_as_subjective(rescored, voters)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::accept_claim_veracity(
+			T::MaxContentPerClaim::get(),
+			T::MaxVotersPerClaim::get(),
+		))]
+		/// Accepts an objective claim as true.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the veracity collective.
+		/// * `claim_id` - Id of the claim.
+		pub fn accept_claim_veracity(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+		) -> DispatchResultWithPostInfo {
+			T::VeracityOrigin::ensure_origin(origin)?;
+			let (rescored, voters) = Self::transition_claim(
+				claim_id,
+				&[ClaimStatus::ObjectivityApproved],
+				ClaimStatus::VeracityAccepted,
+			)?;
+			Self::deposit_event(Event::ClaimVeracityAccepted(claim_id));
+			Ok(Some(T::WeightInfo::accept_claim_veracity(rescored, voters)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::reject_claim_veracity(
+			T::MaxContentPerClaim::get(),
+			T::MaxVotersPerClaim::get(),
+		))]
+		/// Rejects an objective claim as false.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the veracity collective.
+		/// * `claim_id` - Id of the claim.
+		pub fn reject_claim_veracity(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+		) -> DispatchResultWithPostInfo {
+			T::VeracityOrigin::ensure_origin(origin)?;
+			let (rescored, voters) = Self::transition_claim(
+				claim_id,
+				&[ClaimStatus::ObjectivityApproved],
+				ClaimStatus::VeracityRejected,
+			)?;
+			Self::deposit_event(Event::ClaimVeracityRejected(claim_id));
+			Ok(Some(T::WeightInfo::reject_claim_veracity(rescored, voters)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::claim_reward())]
+		/// Pays the caller their share in the reward of a finalized claim they voted on.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The reviewer.
+		/// * `claim_id` - Id of the claim.
+		#[transactional]
+		pub fn claim_reward(origin: OriginFor<T>, claim_id: ClaimId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let payout = Self::take_reward(claim_id, &who).ok_or(Error::<T>::NoReward)?;
+			T::Currency::transfer(
+				&Self::reward_pot(),
+				&who,
+				payout,
+				ExistenceRequirement::KeepAlive,
+			)?;
+			Self::deposit_event(Event::RewardPaid(claim_id, who, payout));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::appeal_claim(T::MaxContentPerClaim::get()))]
+		/// Appeals the final verdict on a claim, so that the `AppealOrigin` decides it again.
+		/// Reserves `AppealBond` from the caller until the appeal is decided.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The appellant.
+		/// * `claim_id` - Id of the claim.
+		#[transactional]
+		pub fn appeal_claim(origin: OriginFor<T>, claim_id: ClaimId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let verdict = Self::get_claim(claim_id).ok_or(Error::<T>::NonExistentClaim)?.status;
+			ensure!(verdict.is_final(), Error::<T>::InvalidClaimTransition);
+			ensure!(
+				Self::verdicts_in_review(claim_id) < T::MaxVerdicts::get(),
+				Error::<T>::TooManyAppeals
+			);
+
+			let bond = T::AppealBond::get();
+			T::Currency::reserve(&who, bond)?;
+			Appeals::<T>::insert(claim_id, Appeal { appellant: who.clone(), bond, verdict });
+			Self::set_claim_status(claim_id, &[verdict], ClaimStatus::UnderAppeal)?;
+			// The verdict on appeal will be re-reviewed in time instead.
+			Self::cancel_review(claim_id);
+			// The claim doesn't count towards the scores while it is under appeal.
+			let rescored = Self::rescore_claim(claim_id);
+
+			Self::deposit_event(Event::ClaimAppealed(claim_id, who));
+			Ok(Some(T::WeightInfo::appeal_claim(rescored)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::decide_appeal(T::MaxContentPerClaim::get()))]
+		/// Decides an appeal by giving the claim a final status. The bond of the appellant is
+		/// returned if the status differs from the appealed verdict, and slashed otherwise. The
+		/// content the claim is raised in is rescored, also if its review is already closed.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the Board.
+		/// * `claim_id` - Id of the claim.
+		/// * `status` - The final status of the claim.
+		#[transactional]
+		pub fn decide_appeal(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+			status: ClaimStatus,
+		) -> DispatchResultWithPostInfo {
+			T::AppealOrigin::ensure_origin(origin)?;
+			ensure!(status.is_final(), Error::<T>::InvalidVerdict);
+			Self::set_claim_status(claim_id, &[ClaimStatus::UnderAppeal], status)?;
+			let appeal = Appeals::<T>::take(claim_id).ok_or(Error::<T>::InvalidClaimTransition)?;
+			let rescored = Self::rescore_claim(claim_id);
+			Self::record_verdict(claim_id, status, true);
+			Self::settle_appeal(claim_id, appeal, status);
+			Ok(Some(T::WeightInfo::decide_appeal(rescored)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_claim_category())]
+		/// Moves a claim to a category. If the claim has a final verdict, its re-review is
+		/// rescheduled according to the review period of the new category.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the Panel.
+		/// * `claim_id` - Id of the claim.
+		/// * `category` - The new category of the claim.
+		pub fn set_claim_category(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+			category: CategoryId,
+		) -> DispatchResult {
+			T::ClaimOrigin::ensure_origin(origin)?;
+			let claim = Self::get_claim(claim_id).ok_or(Error::<T>::NonExistentClaim)?;
+			ClaimCategories::<T>::insert(claim_id, category);
+			if claim.status.is_final() {
+				if let Some(verdict) = Self::last_verdict(claim_id) {
+					Self::schedule_review(claim_id, verdict.decided_at);
+				}
+			}
+			Self::deposit_event(Event::ClaimCategorized(claim_id, category));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::set_review_period())]
+		/// Sets how long the verdicts on claims of a category stand before the claims are reviewed
+		/// again. The period applies to verdicts given from now on, as well as to verdicts on
+		/// claims moved to the category later.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the Board.
+		/// * `category` - The category of claims.
+		/// * `review_period` - Number of blocks the verdicts stand, or `None` to let them stand
+		/// for good.
+		pub fn set_review_period(
+			origin: OriginFor<T>,
+			category: CategoryId,
+			review_period: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			T::ReviewPeriodOrigin::ensure_origin(origin)?;
+			ensure!(
+				review_period.map_or(true, |period| !period.is_zero()),
+				Error::<T>::ZeroReviewPeriod
+			);
+			ReviewPeriods::<T>::mutate(category, |period| *period = review_period);
+			Self::deposit_event(Event::ReviewPeriodSet(category, review_period));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::approve_public_summary())]
+		/// Approves a plain-language summary of a claim and its final verdict, for the media and
+		/// other lay readers. Replaces any summary approved before.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of the Council.
+		/// * `claim_id` - Id of the claim.
+		/// * `summary_cid` - IPFS CID of the summary text.
+		pub fn approve_public_summary(
+			origin: OriginFor<T>,
+			claim_id: ClaimId,
+			summary_cid: Vec<u8>,
+		) -> DispatchResult {
+			T::PublicSummaryOrigin::ensure_origin(origin)?;
+			let summary_cid: BoundedVec<u8, T::MaxCidLength> =
+				summary_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?;
+			let claim = Self::get_claim(claim_id).ok_or(Error::<T>::NonExistentClaim)?;
+			ensure!(claim.status.is_final(), Error::<T>::NoFinalVerdict);
+			PublicSummaries::<T>::insert(
+				claim_id,
+				PublicSummary { summary_cid, verdict: claim.status },
+			);
+			Self::deposit_event(Event::PublicSummaryApproved(claim_id, claim.status));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Deposit reserved for submitting content with the given url.
+		pub fn content_deposit_for(url: &[u8]) -> BalanceOf<T> {
+			T::ContentDepositBase::get()
+				.saturating_add(T::ContentDepositPerByte::get().saturating_mul((url.len() as u32).into()))
+		}
+
+		/// Stores content submitted by `who` and reserves the deposit for it. A correction of
+		/// `corrects` may have the url of the original.
+		fn insert_content(
+			who: T::AccountId,
+			url: Vec<u8>,
+			doi: Option<Vec<u8>>,
+			author: Option<AuthorId>,
+			publisher: Option<PublisherId>,
+			archive_cid: Option<Vec<u8>>,
+			corrects: Option<T::ContentId>,
+		) -> DispatchResult {
+			let url: BoundedVec<u8, T::MaxUrlLength> =
+				url.try_into().map_err(|_| Error::<T>::UrlTooLong)?;
+			let doi: Option<BoundedVec<u8, T::MaxDoiLength>> = doi
+				.map(|doi| {
+					ensure!(crate::doi::is_valid(&doi), Error::<T>::InvalidDoi);
+					doi.try_into().map_err(|_| Error::<T>::DoiTooLong)
+				})
+				.transpose()?;
+			let archive_cid: Option<BoundedVec<u8, T::MaxCidLength>> = archive_cid
+				.map(|cid| cid.try_into().map_err(|_| Error::<T>::CidTooLong))
+				.transpose()?;
+			if let Some(author) = author {
+				ensure!(Authors::<T>::contains_key(author), Error::<T>::NonExistentAuthor);
+			}
+			if let Some(publisher) = publisher {
+				ensure!(Publishers::<T>::contains_key(publisher), Error::<T>::NonExistentPublisher);
+			}
+			ensure!(
+				Self::find_duplicate(&url, archive_cid.as_ref().map(|cid| &cid[..]), corrects)
+					.is_none(),
+				Error::<T>::DuplicateContent
+			);
+			let deposit = Self::content_deposit_for(&url);
+			T::Currency::reserve(&who, deposit)?;
+			let class_id =
+				NextContentId::<T>::try_mutate(|id| -> Result<T::ContentId, DispatchError> {
+					let current_id = *id;
+					*id = id.checked_add(&One::one()).ok_or(Error::<T>::NoAvailableContentId)?;
+					Ok(current_id)
+				})?;
+			ContentDeposits::<T>::insert(class_id, (who.clone(), deposit));
+
+			let content = Content {
+				url,
+				claims: Default::default(),
+				score: None,
+				author,
+				publisher,
+				status: ContentStatus::Submitted,
+				submitter: who,
+				doi,
+				archive_cid,
+			};
+			Self::index_fingerprints(
+				class_id,
+				&content.url,
+				content.archive_cid.as_ref().map(|cid| &cid[..]),
+			);
+			ContentStorage::<T>::insert(class_id.clone(), content);
+			Self::deposit_event(Event::ContentStored(class_id));
+			Ok(())
+		}
+
+		/// Looks up an existing claim with the given text.
+		pub fn find_claim(claim_text_cid: &[u8]) -> Option<ClaimId> {
+			Self::claim_by_text_hash(T::Hashing::hash(claim_text_cid))
+		}
+
+		/// Links the claim to the content in both directions. The first claim raised in submitted
+		/// content starts its review.
+		fn link_claim(claim_id: ClaimId, content_id: T::ContentId) -> DispatchResult {
+			let links = ClaimsToContent::<T>::iter_prefix(claim_id).count() as u32;
+			ensure!(links < T::MaxContentPerClaim::get(), Error::<T>::TooManyContentLinks);
+			// add claim id to content for future reference
+			let started = ContentStorage::<T>::try_mutate(content_id, |content| {
+				ensure!(content.status.is_open(), Error::<T>::ReviewConcluded);
+				content.claims.try_push(claim_id).map_err(|_| Error::<T>::TooManyClaims)?;
+				let started = content.status == ContentStatus::Submitted;
+				content.status = ContentStatus::InReview;
+				Ok::<_, DispatchError>(started)
+			})?;
+			ClaimsToContent::<T>::insert(claim_id, content_id, ());
+			if started {
+				let ends = Self::open_review(content_id);
+				Self::deposit_event(Event::ContentReviewStarted(content_id, ends));
+			}
+			Ok(())
+		}
+
+		/// Unlinks the claim from the content. A claim which is no longer raised in any content
+		/// is removed along with its votes, schedule and summary, and a pending appeal against it
+		/// is dropped with the bond returned. Its verdict history is kept.
+		pub(crate) fn unlink_claim(claim_id: ClaimId, content_id: T::ContentId) {
+			ClaimsToContent::<T>::remove(claim_id, content_id);
+			if ClaimsToContent::<T>::iter_prefix(claim_id).next().is_some() {
+				return
+			}
+			let claim = match Claims::<T>::take(claim_id) {
+				Some(claim) => claim,
+				None => return,
+			};
+			let text_hash = T::Hashing::hash(&claim.claim_text_cid);
+			ClaimByTextHash::<T>::mutate_exists(text_hash, |indexed| {
+				if *indexed == Some(claim_id) {
+					*indexed = None;
+				}
+			});
+			let _ = ClaimVotes::<T>::remove_prefix(claim_id, None);
+			ClaimVoterCount::<T>::remove(claim_id);
+			ClaimCategories::<T>::remove(claim_id);
+			PublicSummaries::<T>::remove(claim_id);
+			Self::cancel_review(claim_id);
+			if let Some(appeal) = Appeals::<T>::take(claim_id) {
+				T::Currency::unreserve(&appeal.appellant, appeal.bond);
+			}
+			Self::deposit_event(Event::ClaimRemoved(claim_id));
+		}
+
+		/// Builds a bounded author or publisher record.
+		fn source(name: Vec<u8>, accounts: Vec<T::AccountId>) -> Result<SourceOf<T>, DispatchError> {
+			Ok(Source {
+				name: name.try_into().map_err(|_| Error::<T>::CidTooLong)?,
+				accounts: accounts.try_into().map_err(|_| Error::<T>::TooManySourceAccounts)?,
+			})
+		}
+
+		/// Moves a claim in one of the `from` statuses to the `to` status. Once it is final, all
+		/// content it is linked to is rescored and the reputation of its voters is updated.
+		///
+		/// Returns the number of rescored content items and of voters.
+		fn transition_claim(
+			claim_id: ClaimId,
+			from: &[ClaimStatus],
+			to: ClaimStatus,
+		) -> Result<(u32, u32), DispatchError> {
+			Self::set_claim_status(claim_id, from, to)?;
+			let mut rescored = 0;
+			let mut voters = 0;
+			if to.is_final() {
+				rescored = Self::rescore_claim(claim_id);
+				Self::record_verdict(claim_id, to, false);
+				voters = Self::settle_votes(claim_id, to);
+				Self::deposit_event(Event::ReputationSettled(claim_id, voters));
+			}
+			Ok((rescored, voters))
+		}
+
+		/// Sets the status of a claim in one of the `from` statuses to `to`.
+		pub(crate) fn set_claim_status(
+			claim_id: ClaimId,
+			from: &[ClaimStatus],
+			to: ClaimStatus,
+		) -> DispatchResult {
+			Claims::<T>::try_mutate(claim_id, |maybe_claim| -> DispatchResult {
+				let claim = maybe_claim.as_mut().ok_or(Error::<T>::NonExistentClaim)?;
+				ensure!(from.contains(&claim.status), Error::<T>::InvalidClaimTransition);
+				claim.status = to;
+				Ok(())
+			})
+		}
+
+		/// Rescores all content the claim is linked to. Returns the number of content items.
+		pub(crate) fn rescore_claim(claim_id: ClaimId) -> u32 {
+			let mut rescored = 0;
+			for (content_id, ()) in ClaimsToContent::<T>::iter_prefix(claim_id) {
+				Self::update_score(content_id);
+				rescored += 1;
+			}
+			rescored
+		}
+
+		/// Tallies the finalized claims of the content.
+		pub fn claim_tally(content_id: T::ContentId) -> ClaimTally {
+			let mut tally = ClaimTally::default();
+			for claim_id in Self::get_content(content_id).claims.iter() {
+				match Self::get_claim(claim_id).map(|claim| claim.status) {
+					Some(ClaimStatus::VeracityAccepted) => tally.accepted += 1,
+					Some(ClaimStatus::VeracityRejected) => tally.rejected += 1,
+					Some(ClaimStatus::RejectedAsSubjective) => tally.subjective += 1,
+					_ => {},
+				}
+			}
+			tally
+		}
+
+		/// Recomputes the score of the content from its claims using the `ScoringPolicy`, and the
+		/// credibility of its author and publisher along with it. Content whose review is closed
+		/// stays `Scored`, but its score follows later verdicts, e.g. on appeal or re-review.
+		pub(crate) fn update_score(content_id: T::ContentId) {
+			let score = T::ScoringPolicy::score(&Self::claim_tally(content_id));
+			ContentStorage::<T>::mutate(content_id, |content| {
+				if content.score == score {
+					return
+				}
+				if let Some(author) = content.author {
+					AuthorCredibility::<T>::mutate(author, |c| c.rescore(content.score, score));
+				}
+				if let Some(publisher) = content.publisher {
+					PublisherCredibility::<T>::mutate(publisher, |c| c.rescore(content.score, score));
+				}
+				content.score = score;
+				if let Some(score) = score {
+					Self::deposit_event(Event::ContentScoreUpdated(content_id, score));
+				}
+			});
+		}
 	}
 }
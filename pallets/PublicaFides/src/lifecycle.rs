@@ -0,0 +1,93 @@
+//! Review windows of content.
+//!
+//! Content is `Submitted` until the first claim is raised in it. It is then `InReview` for
+//! `ReviewPeriod` blocks, during which more claims can be raised in it and voted on. Once the
+//! review window ends, `on_idle` closes the review as far as the remaining weight of the block
+//! allows: the content is scored from the verdicts on its claims so far, the deposit is returned
+//! and the content is `Scored`. The `ReviewOrigin` can close a review early with
+//! `complete_review`, or reject spam with `mark_as_spam`.
+//!
+//! No more claims can be raised in content once its review is closed, but verdicts given later,
+//! e.g. on appeal or re-review, still rescore it and emit `ContentScoreUpdated`.
+
+use crate::{
+	Config, ContentDeposits, ContentReviewQueue, ContentStatus, ContentStorage, Error, Event,
+	NextContentReviewBlock, Pallet, ReviewEnds, WeightInfo,
+};
+use frame_support::{
+	dispatch::DispatchResult,
+	ensure,
+	traits::{Get, ReservableCurrency},
+	weights::Weight,
+};
+use sp_runtime::traits::{One, Saturating};
+
+impl<T: Config> Pallet<T> {
+	/// Opens the review window of content, ending `ReviewPeriod` blocks from now. Returns the
+	/// block in which the window ends.
+	pub(crate) fn open_review(content_id: T::ContentId) -> T::BlockNumber {
+		let now = frame_system::Pallet::<T>::block_number();
+		let ends = now.saturating_add(T::ReviewPeriod::get().max(One::one()));
+		ReviewEnds::<T>::insert(content_id, ends);
+		ContentReviewQueue::<T>::insert(ends, content_id, ());
+		ends
+	}
+
+	/// Takes the review window of the content out of the queue, if it is open.
+	pub(crate) fn cancel_review_window(content_id: T::ContentId) {
+		if let Some(ends) = ReviewEnds::<T>::take(content_id) {
+			ContentReviewQueue::<T>::remove(ends, content_id);
+		}
+	}
+
+	/// Scores content under review from the verdicts on its claims so far, returns its deposit and
+	/// marks it as `Scored`.
+	pub(crate) fn close_review(content_id: T::ContentId) -> DispatchResult {
+		ensure!(Self::get_content(content_id).status.is_open(), Error::<T>::ReviewConcluded);
+		Self::update_score(content_id);
+		ContentStorage::<T>::mutate(content_id, |content| content.status = ContentStatus::Scored);
+		Self::cancel_review_window(content_id);
+		if let Some((who, deposit)) = ContentDeposits::<T>::take(content_id) {
+			T::Currency::unreserve(&who, deposit);
+		}
+		Self::deposit_event(Event::ContentReviewCompleted(content_id));
+		Ok(())
+	}
+
+	/// Closes the reviews whose windows ended up to block `now`, oldest first, as far as
+	/// `remaining_weight` allows.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn close_ended_reviews(now: T::BlockNumber, remaining_weight: Weight) -> Weight {
+		let weight = |closed: u32, searched: u32| {
+			T::WeightInfo::close_reviews(closed)
+				.saturating_add(T::DbWeight::get().reads(searched.into()))
+		};
+		// Whether there is weight left to either close one more review or search one more block.
+		let fits = |closed, searched| weight(closed + 1, searched + 1) <= remaining_weight;
+		if !fits(0, 0) {
+			return 0
+		}
+		let mut block = NextContentReviewBlock::<T>::get();
+		let mut closed = 0;
+		let mut searched = 0;
+		while block <= now && fits(closed, searched) {
+			match ContentReviewQueue::<T>::iter_prefix(block).next() {
+				Some((content_id, ())) => {
+					// Reviews leave the queue once they are closed, but an entry left behind
+					// must not stall the queue.
+					if Self::close_review(content_id).is_err() {
+						ContentReviewQueue::<T>::remove(block, content_id);
+					}
+					closed += 1;
+				},
+				None => {
+					block = block.saturating_add(One::one());
+					searched += 1;
+				},
+			}
+		}
+		NextContentReviewBlock::<T>::put(block);
+		weight(closed, searched)
+	}
+}
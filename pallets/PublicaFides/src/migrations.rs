@@ -0,0 +1,159 @@
+//! Storage migrations for the PublicaFides pallet.
+
+/// Migration from the storage of the first release to version 1.
+pub mod v1 {
+	use crate::{
+		Claim, ClaimByTextHash, ClaimId, ClaimStatus, Claims, ClaimsToContent, Config, Content,
+		ContentByFingerprint, ContentStatus, ContentStorage, NextContentReviewBlock,
+		NextReviewBlock, Pallet, ScoringPolicy,
+	};
+	use codec::{Decode, Encode};
+	use frame_support::{
+		traits::{Get, StorageVersion},
+		weights::Weight,
+	};
+	use sp_runtime::traits::Hash;
+	use sp_std::{
+		collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+		convert::TryInto,
+		vec::Vec,
+	};
+
+	/// Content as stored before version 1.
+	#[derive(Encode, Decode)]
+	pub struct OldContent {
+		pub url: Vec<u8>,
+		pub claims: Vec<ClaimId>,
+	}
+
+	/// Claim as stored in `ClaimsToContent` before version 1, along with its link to content.
+	#[derive(Encode, Decode)]
+	pub struct OldClaim {
+		pub claim_text_cid: Vec<u8>,
+		pub is_accepted: bool,
+	}
+
+	/// Moves the pallet storage to bounded types and the current layout.
+	///
+	/// Claims were stored in `ClaimsToContent` along with their link to content, accepted or
+	/// rejected as true. They are moved to `Claims` with the matching final status and indexed by
+	/// their text, and their verdict is recorded.
+	///
+	/// Content had no score, author, publisher, submitter, DOI or archive CID before, and no
+	/// deposit was taken for it. Lists of claims that don't fit are truncated, content with claims
+	/// is scored from its final claims, and all content is `Scored`, as its review can't be told
+	/// apart from a concluded one. The submitter is left as the default account. Content is
+	/// indexed by the fingerprint of its url, and of content submitted more than once only one
+	/// submission is found by it.
+	///
+	/// Content with an oversized url and claims with an oversized CID can't be truncated
+	/// meaningfully, so they are rejected: they are removed along with their links, and claims
+	/// only raised in rejected content are removed too.
+	///
+	/// The searches for expired verdicts and ended reviews start at the current block rather than
+	/// at genesis.
+	pub fn migrate<T: Config>() -> Weight {
+		if StorageVersion::get::<Pallet<T>>() >= StorageVersion::new(1) {
+			return T::DbWeight::get().reads(1)
+		}
+		let mut reads: Weight = 1;
+		let mut writes: Weight = 3;
+
+		let mut rejected_claims = BTreeSet::new();
+		let mut verdicts = BTreeMap::new();
+		ClaimsToContent::<T>::translate::<OldClaim, _>(|claim_id, _, old| {
+			reads += 1;
+			writes += 1;
+			let text_hash = T::Hashing::hash(&old.claim_text_cid);
+			let claim_text_cid = match old.claim_text_cid.try_into() {
+				Ok(claim_text_cid) => claim_text_cid,
+				Err(_) => {
+					rejected_claims.insert(claim_id);
+					return None
+				},
+			};
+			// The claim was stored along with each of its links, the first one is kept.
+			if verdicts.contains_key(&claim_id) {
+				return Some(())
+			}
+			let status = if old.is_accepted {
+				ClaimStatus::VeracityAccepted
+			} else {
+				ClaimStatus::VeracityRejected
+			};
+			writes += 2;
+			Claims::<T>::insert(claim_id, Claim { claim_text_cid, status });
+			// Claims with the same text were raised separately before. The first one is found by
+			// its text from now on.
+			if !ClaimByTextHash::<T>::contains_key(text_hash) {
+				ClaimByTextHash::<T>::insert(text_hash, claim_id);
+			}
+			verdicts.insert(claim_id, status);
+			Some(())
+		});
+		for (claim_id, status) in verdicts {
+			reads += 4;
+			writes += 3;
+			Pallet::<T>::record_verdict(claim_id, status, false);
+		}
+
+		let mut unlinked_claims = Vec::new();
+		let mut scored_content = Vec::new();
+		ContentStorage::<T>::translate::<OldContent, _>(|content_id, old| {
+			reads += 1;
+			writes += 1;
+			let mut claims: Vec<ClaimId> =
+				old.claims.into_iter().filter(|c| !rejected_claims.contains(c)).collect();
+			let url = match old.url.try_into() {
+				Ok(url) => url,
+				Err(_) => {
+					unlinked_claims.extend(claims.into_iter().map(|c| (c, content_id)));
+					return None
+				},
+			};
+			let max_claims = T::MaxClaimsPerContent::get() as usize;
+			unlinked_claims.extend(claims.iter().skip(max_claims).map(|c| (*c, content_id)));
+			claims.truncate(max_claims);
+			if !claims.is_empty() {
+				scored_content.push(content_id);
+			}
+			reads += 1;
+			let fingerprint = Pallet::<T>::url_fingerprint(&url);
+			if !ContentByFingerprint::<T>::contains_key(fingerprint) {
+				writes += 1;
+				ContentByFingerprint::<T>::insert(fingerprint, content_id);
+			}
+			Some(Content {
+				url,
+				// Can't fail, the claims were truncated to the bound.
+				claims: claims.try_into().unwrap_or_default(),
+				score: None,
+				author: None,
+				publisher: None,
+				status: ContentStatus::Scored,
+				submitter: Default::default(),
+				doi: None,
+				archive_cid: None,
+			})
+		});
+		for (claim_id, content_id) in unlinked_claims {
+			reads += 2;
+			writes += 3;
+			Pallet::<T>::unlink_claim(claim_id, content_id);
+		}
+		// The content has no author or publisher whose credibility would follow the score.
+		let max_claims = T::MaxClaimsPerContent::get() as Weight;
+		for content_id in scored_content {
+			reads += 2 + max_claims;
+			writes += 1;
+			let score = T::ScoringPolicy::score(&Pallet::<T>::claim_tally(content_id));
+			ContentStorage::<T>::mutate(content_id, |content| content.score = score);
+		}
+
+		let now = frame_system::Pallet::<T>::block_number();
+		NextReviewBlock::<T>::put(now);
+		NextContentReviewBlock::<T>::put(now);
+		StorageVersion::new(1).put::<Pallet<T>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+}
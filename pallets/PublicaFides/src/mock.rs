@@ -1,12 +1,16 @@
-use super::*;
-use crate as ;
-use frame_support::parameter_types;
+use crate as publicafides;
+use frame_support::{parameter_types, traits::Get, PalletId};
 use frame_system as system;
-use sp_core::H256;
+use sp_core::{
+	u32_trait::{_1, _2},
+	H256,
+};
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	Perbill,
 };
+use std::cell::RefCell;
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
 type Block = frame_system::mocking::MockBlock<Test>;
@@ -19,7 +23,10 @@ frame_support::construct_runtime!(
 		UncheckedExtrinsic = UncheckedExtrinsic,
 	{
 		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
-		PublicaFides: ::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		PublicaFides: publicafides::{Pallet, Call, Storage, Event<T>},
+		Panel: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
+		Committee: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
 	}
 );
 
@@ -46,7 +53,7 @@ impl system::Config for Test {
 	type BlockHashCount = BlockHashCount;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -54,12 +61,149 @@ impl system::Config for Test {
 	type OnSetCode = ();
 }
 
-impl ::Config for Test {
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const PanelMotionDuration: u64 = 3;
+	pub const PanelMaxProposals: u32 = 100;
+	pub const PanelMaxMembers: u32 = 10;
+}
+
+pub type PanelCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<PanelCollective> for Test {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = PanelMotionDuration;
+	type MaxProposals = PanelMaxProposals;
+	type MaxMembers = PanelMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MembershipFilter = frame_support::traits::Everything;
+	type OnProposalClosed = PublicaFides;
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = ();
+	type VoteWeight = ();
+	type MaxDelegationDepth = ();
+	type WeightInfo = ();
+}
+
+pub type CommitteeCollective = pallet_collective::Instance2;
+
+impl pallet_collective::Config<CommitteeCollective> for Test {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = PanelMotionDuration;
+	type MaxProposals = PanelMaxProposals;
+	type MaxMembers = PanelMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MembershipFilter = frame_support::traits::Everything;
+	type OnProposalClosed = PublicaFides;
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = ();
+	type VoteWeight = ();
+	type MaxDelegationDepth = ();
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const ContentDepositBase: u64 = 10;
+	pub const ContentDepositPerByte: u64 = 1;
+	pub const MaxUrlLength: u32 = 16;
+	pub const MaxDoiLength: u32 = 12;
+	pub const MaxCidLength: u32 = 8;
+	pub const MaxClaimsPerContent: u32 = 3;
+	pub const MaxSourceAccounts: u32 = 2;
+	pub const MaxContentPerClaim: u32 = 4;
+	pub const ReputationGain: u32 = 10;
+	pub const ReputationDecay: Perbill = Perbill::from_percent(50);
+	pub const MaxVotersPerClaim: u32 = 4;
+	pub const PublicaFidesPalletId: PalletId = PalletId(*b"py/pfrwd");
+	pub const RewardPerClaim: u64 = 20;
+	pub const AppealBond: u64 = 30;
+	pub const MaxVerdicts: u32 = 3;
+	pub const MaxReReviewsPerBlock: u32 = 2;
+	pub const ReviewPeriod: u64 = 10;
+}
+
+thread_local! {
+	static REWARD_INFLATION: RefCell<u64> = RefCell::new(0);
+}
+
+/// Minted into the reward pot every block, zero unless changed with `set_reward_inflation`.
+pub struct RewardInflation;
+
+impl Get<u64> for RewardInflation {
+	fn get() -> u64 {
+		REWARD_INFLATION.with(|inflation| *inflation.borrow())
+	}
+}
+
+pub fn set_reward_inflation(inflation: u64) {
+	REWARD_INFLATION.with(|current| *current.borrow_mut() = inflation);
+}
+
+impl publicafides::Config for Test {
 	type Event = Event;
-	type ArticleId = u32;
+	type ContentId = u32;
+	type ContentOrigin = frame_system::EnsureSigned<u64>;
+	type ClaimOrigin = pallet_collective::EnsureProportionAtLeast<_1, _2, u64, PanelCollective>;
+	type ObjectivityOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, u64, PanelCollective>;
+	type VeracityOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, u64, CommitteeCollective>;
+	type ScoringPolicy = publicafides::AcceptedRatio;
+	type SourceOrigin = frame_system::EnsureRoot<u64>;
+	type Currency = Balances;
+	type ContentDepositBase = ContentDepositBase;
+	type ContentDepositPerByte = ContentDepositPerByte;
+	type SpamSlashed = PublicaFides;
+	type ReviewOrigin = frame_system::EnsureRoot<u64>;
+	type ReviewPeriod = ReviewPeriod;
+	type MaxUrlLength = MaxUrlLength;
+	type MaxDoiLength = MaxDoiLength;
+	type MaxCidLength = MaxCidLength;
+	type MaxClaimsPerContent = MaxClaimsPerContent;
+	type MaxSourceAccounts = MaxSourceAccounts;
+	type MaxContentPerClaim = MaxContentPerClaim;
+	type ReputationGain = ReputationGain;
+	type ReputationDecay = ReputationDecay;
+	type MaxVotersPerClaim = MaxVotersPerClaim;
+	type PalletId = PublicaFidesPalletId;
+	type RewardPerClaim = RewardPerClaim;
+	type RewardInflation = RewardInflation;
+	type AppealOrigin = frame_system::EnsureRoot<u64>;
+	type AppealBond = AppealBond;
+	type MaxVerdicts = MaxVerdicts;
+	type ReviewPeriodOrigin = frame_system::EnsureRoot<u64>;
+	type MaxReReviewsPerBlock = MaxReReviewsPerBlock;
+	type PublicSummaryOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, u64, CommitteeCollective>;
+	type WeightInfo = ();
 }
 
 // Build genesis storage according to the mock runtime.
 pub fn new_test_ext() -> sp_io::TestExternalities {
-	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100), (3, 5)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
 }
@@ -0,0 +1,174 @@
+//! Reputation of reviewers, tracking how their votes on claims compare to the final verdicts.
+//!
+//! The Panel and Committee report the votes on motions about claims through
+//! `pallet_collective::OnProposalClosed`. Only the collective whose origin decides a question,
+//! `ObjectivityOrigin` or `VeracityOrigin`, has its votes on it counted. The votes are kept in
+//! `ClaimVotes` until the claim receives its final verdict. Then every reviewer who voted in line
+//! with the verdict gains `ReputationGain`, and every reviewer who voted against it loses
+//! `ReputationDecay` of their reputation.
+//!
+//! As the `VoteWeight` of the collectives, reputation also gives experienced reviewers more say:
+//! every reviewer has one vote, plus one for every `ReputationGain` of reputation.
+
+use crate::{
+	ClaimId, ClaimStatus, ClaimVoterCount, ClaimVotes, Claims, Config, Pallet, ReviewerReputation,
+	WeightInfo,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{EnsureOrigin, Get, IsSubType},
+	weights::Weight,
+};
+use pallet_collective::{MemberCount, OnProposalClosed, VoteWeight};
+use sp_runtime::{PerThing, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// How a reviewer voted on a claim. `None` where the reviewer didn't vote.
+#[derive(Encode, Decode, Default, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct ClaimVote {
+	/// Whether the reviewer voted the claim to be an objective statement.
+	pub objective: Option<bool>,
+	/// Whether the reviewer voted the claim to be true.
+	pub accepted: Option<bool>,
+}
+
+impl ClaimVote {
+	/// Whether the vote is in line with the final `status` of the claim, or `None` if the
+	/// reviewer voted on nothing the status decides. A vote against any part of the verdict goes
+	/// against the verdict.
+	pub fn agrees_with(&self, status: ClaimStatus) -> Option<bool> {
+		let (objective, accepted) = match status {
+			ClaimStatus::RejectedAsSubjective => (false, None),
+			ClaimStatus::VeracityAccepted => (true, Some(true)),
+			ClaimStatus::VeracityRejected => (true, Some(false)),
+			ClaimStatus::Proposed |
+			ClaimStatus::ObjectivityApproved |
+			ClaimStatus::UnderAppeal |
+			ClaimStatus::PendingReReview => return None,
+		};
+		let answers = [
+			self.objective.map(|vote| vote == objective),
+			self.accepted.and_then(|vote| accepted.map(|verdict| vote == verdict)),
+		];
+		answers
+			.iter()
+			.flatten()
+			.fold(None, |agrees, answer| Some(agrees.unwrap_or(true) && *answer))
+	}
+}
+
+/// The question a motion about a claim decides.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum Question {
+	/// Whether the claim is an objective statement.
+	Objectivity,
+	/// Whether the claim is true.
+	Veracity,
+}
+
+impl<T: Config> Pallet<T> {
+	/// Records the votes on a motion about the claim, where an aye vote answers `question` with
+	/// `aye`. Votes of reviewers beyond `MaxVotersPerClaim` are not recorded.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn record_votes(
+		claim_id: ClaimId,
+		question: Question,
+		aye: bool,
+		ayes: &[T::AccountId],
+		nays: &[T::AccountId],
+	) -> Weight {
+		// Votes on claims that already have their verdict can never be settled.
+		if !Claims::<T>::get(claim_id).map_or(false, |claim| !claim.status.is_final()) {
+			return T::WeightInfo::record_votes(0)
+		}
+		let mut count = ClaimVoterCount::<T>::get(claim_id);
+		let votes = ayes.iter().map(|who| (who, aye)).chain(nays.iter().map(|who| (who, !aye)));
+		for (who, answer) in votes {
+			ClaimVotes::<T>::mutate_exists(claim_id, who, |maybe_vote| {
+				if maybe_vote.is_none() {
+					if count >= T::MaxVotersPerClaim::get() {
+						return
+					}
+					count += 1;
+				}
+				let vote = maybe_vote.get_or_insert_with(Default::default);
+				match question {
+					Question::Objectivity => vote.objective = Some(answer),
+					Question::Veracity => vote.accepted = Some(answer),
+				}
+			});
+		}
+		ClaimVoterCount::<T>::insert(claim_id, count);
+		T::WeightInfo::record_votes((ayes.len() + nays.len()) as u32)
+	}
+
+	/// Updates the reputation of everyone who voted on the claim according to its final
+	/// `status`, forgets their votes and allocates their rewards. Returns the number of voters.
+	pub(crate) fn settle_votes(claim_id: ClaimId, status: ClaimStatus) -> u32 {
+		let mut voters = Vec::new();
+		for (who, vote) in ClaimVotes::<T>::drain_prefix(claim_id) {
+			let reputation = ReviewerReputation::<T>::mutate(&who, |reputation| {
+				match vote.agrees_with(status) {
+					Some(true) => *reputation = reputation.saturating_add(T::ReputationGain::get()),
+					Some(false) =>
+						*reputation = reputation
+							.saturating_sub(T::ReputationDecay::get().mul_ceil(*reputation)),
+					None => {},
+				}
+				*reputation
+			});
+			voters.push((who, reputation));
+		}
+		ClaimVoterCount::<T>::remove(claim_id);
+		Self::allocate_rewards(claim_id, &voters);
+		voters.len() as u32
+	}
+}
+
+impl<T: Config, Proposal: IsSubType<crate::Call<T>>>
+	OnProposalClosed<T::AccountId, Proposal, T::Origin> for Pallet<T>
+{
+	fn on_proposal_closed(
+		origin: T::Origin,
+		proposal: &Proposal,
+		_approved: bool,
+		ayes: &[T::AccountId],
+		nays: &[T::AccountId],
+	) -> Weight {
+		let (claim_id, question, aye) = match proposal.is_sub_type() {
+			Some(crate::Call::approve_claim_objectivity(claim_id)) =>
+				(*claim_id, Question::Objectivity, true),
+			Some(crate::Call::reject_claim_as_subjective(claim_id)) =>
+				(*claim_id, Question::Objectivity, false),
+			Some(crate::Call::accept_claim_veracity(claim_id)) =>
+				(*claim_id, Question::Veracity, true),
+			Some(crate::Call::reject_claim_veracity(claim_id)) =>
+				(*claim_id, Question::Veracity, false),
+			_ => return 0,
+		};
+		let decides = match question {
+			Question::Objectivity => T::ObjectivityOrigin::try_origin(origin).is_ok(),
+			Question::Veracity => T::VeracityOrigin::try_origin(origin).is_ok(),
+		};
+		if !decides {
+			return 0
+		}
+		Self::record_votes(claim_id, question, aye, ayes, nays)
+	}
+
+	fn max_weight(members: MemberCount) -> Weight {
+		T::WeightInfo::record_votes(members)
+	}
+}
+
+impl<T: Config> VoteWeight<T::AccountId> for Pallet<T> {
+	fn vote_weight(who: &T::AccountId) -> MemberCount {
+		let gain = T::ReputationGain::get().max(1);
+		(ReviewerReputation::<T>::get(who) / gain).saturating_add(1)
+	}
+
+	fn max_weight(members: MemberCount) -> Weight {
+		T::DbWeight::get().reads(members.into())
+	}
+}
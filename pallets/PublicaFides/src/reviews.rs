@@ -0,0 +1,81 @@
+//! Periodic re-review of the verdicts on claims.
+//!
+//! Every claim belongs to a category, 0 unless the `ClaimOrigin` sets another one with
+//! `set_claim_category`. The `ReviewPeriodOrigin` can give a category a review period with
+//! `set_review_period`. A final verdict on a claim of such a category then stands for the review
+//! period, after which `on_initialize` moves the claim to `PendingReReview` and emits
+//! `ClaimPendingReReview`. At most `MaxReReviewsPerBlock` claims are moved per block, the rest
+//! follow in the next blocks.
+//!
+//! Claims pending re-review don't count towards the scores of their content, even content whose
+//! review is closed, and are reviewed again from the objectivity vote on, just like proposed
+//! claims. The new verdict rescores the content.
+
+use crate::{
+	CategoryId, ClaimCategories, ClaimId, ClaimStatus, Claims, Config, Event, NextReviewBlock,
+	Pallet, ReviewDue, ReviewPeriods, ReviewQueue, WeightInfo,
+};
+use frame_support::{traits::Get, weights::Weight};
+use sp_runtime::traits::{One, Saturating};
+
+impl<T: Config> Pallet<T> {
+	/// Schedules the re-review of the verdict on the claim given in `decided_at`, according to
+	/// the review period of its category. Replaces any re-review scheduled before.
+	pub(crate) fn schedule_review(claim_id: ClaimId, decided_at: T::BlockNumber) {
+		Self::cancel_review(claim_id);
+		let category: CategoryId = ClaimCategories::<T>::get(claim_id);
+		if let Some(period) = ReviewPeriods::<T>::get(category) {
+			// Reviews due in the past, e.g. after a category change, are caught up with next block.
+			let next_block = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+			let due = decided_at.saturating_add(period).max(next_block);
+			ReviewDue::<T>::insert(claim_id, due);
+			ReviewQueue::<T>::insert(due, claim_id, ());
+		}
+	}
+
+	/// Cancels the scheduled re-review of the claim, if any.
+	pub(crate) fn cancel_review(claim_id: ClaimId) {
+		if let Some(due) = ReviewDue::<T>::take(claim_id) {
+			ReviewQueue::<T>::remove(due, claim_id);
+		}
+	}
+
+	/// Moves the claims whose verdicts expired up to block `now` to `PendingReReview`, oldest
+	/// first. Every moved claim and every block found without expired verdicts counts towards
+	/// `MaxReReviewsPerBlock`.
+	///
+	/// Returns the weight consumed.
+	pub(crate) fn expire_verdicts(now: T::BlockNumber) -> Weight {
+		let mut block = NextReviewBlock::<T>::get();
+		let mut steps = 0;
+		let mut expired = 0;
+		while block <= now && steps < T::MaxReReviewsPerBlock::get() {
+			steps += 1;
+			match ReviewQueue::<T>::iter_prefix(block).next() {
+				Some((claim_id, ())) => {
+					ReviewQueue::<T>::remove(block, claim_id);
+					ReviewDue::<T>::remove(claim_id);
+					Self::expire_verdict(claim_id);
+					expired += 1;
+				},
+				None => block = block.saturating_add(One::one()),
+			}
+		}
+		NextReviewBlock::<T>::put(block);
+		T::WeightInfo::expire_verdicts(expired)
+			.saturating_add(T::DbWeight::get().reads((steps - expired).into()))
+	}
+
+	/// Moves a claim with a final verdict to `PendingReReview` and rescores its content.
+	fn expire_verdict(claim_id: ClaimId) {
+		// Re-reviews are cancelled when a verdict is appealed, so the claim has its verdict.
+		let verdict = match Claims::<T>::get(claim_id) {
+			Some(claim) if claim.status.is_final() => claim.status,
+			_ => return,
+		};
+		if Self::set_claim_status(claim_id, &[verdict], ClaimStatus::PendingReReview).is_ok() {
+			Self::rescore_claim(claim_id);
+			Self::deposit_event(Event::ClaimPendingReReview(claim_id, verdict));
+		}
+	}
+}
@@ -0,0 +1,107 @@
+//! Rewards of reviewers for their votes on finalized claims.
+//!
+//! The pallet owns a reward pot at the account derived from `PalletId`. The pot is funded by the
+//! deposits slashed from spam submissions, with the pallet as the `SpamSlashed` handler, and by
+//! `RewardInflation` minted into it every block.
+//!
+//! Once a claim receives its final verdict, up to `RewardPerClaim` of the unallocated funds in the
+//! pot is set aside for it. Every reviewer who voted on the claim gets a share of the reward in
+//! proportion to their reputation after the settlement, which they collect with `claim_reward`.
+//! Paying out lazily keeps the cost of the verdict independent of the transfers to the voters.
+
+use crate::{
+	AllocatedRewards, BalanceOf, ClaimId, ClaimRewards, Config, Event, NegativeImbalanceOf, Pallet,
+	Reputation, RewardShares,
+};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{Currency, Get, OnUnbalanced};
+use sp_runtime::{
+	helpers_128bit::multiply_by_rational,
+	traits::{AccountIdConversion, Saturating, Zero},
+	RuntimeDebug, SaturatedConversion,
+};
+
+/// The reward set aside for the voters on a claim which weren't paid out yet.
+#[derive(Encode, Decode, Default, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct ClaimReward<Balance> {
+	/// The part of the reward not paid out yet.
+	pub remaining: Balance,
+	/// The sum of the shares not paid out yet.
+	pub shares: u64,
+}
+
+impl<T: Config> Pallet<T> {
+	/// The account of the reward pot.
+	pub fn reward_pot() -> T::AccountId {
+		T::PalletId::get().into_account()
+	}
+
+	/// The funds in the pot which are not set aside for any claim yet.
+	pub fn unallocated_rewards() -> BalanceOf<T> {
+		T::Currency::free_balance(&Self::reward_pot())
+			.saturating_sub(T::Currency::minimum_balance())
+			.saturating_sub(AllocatedRewards::<T>::get())
+	}
+
+	/// Sets aside the reward for the voters on the claim, given as reviewers with their reputation.
+	/// Reviewers without reputation get no share. Rewards from earlier reviews of the claim which
+	/// weren't paid out yet are added to.
+	pub(crate) fn allocate_rewards(claim_id: ClaimId, voters: &[(T::AccountId, Reputation)]) {
+		let reward = T::RewardPerClaim::get().min(Self::unallocated_rewards());
+		if reward.is_zero() {
+			return
+		}
+		let mut shares = 0u64;
+		for (who, reputation) in voters.iter().filter(|(_, reputation)| *reputation > 0) {
+			RewardShares::<T>::mutate(claim_id, who, |share| {
+				*share = Some(share.unwrap_or(0).saturating_add(*reputation))
+			});
+			shares = shares.saturating_add((*reputation).into());
+		}
+		if shares == 0 {
+			return
+		}
+		AllocatedRewards::<T>::mutate(|allocated| *allocated = allocated.saturating_add(reward));
+		ClaimRewards::<T>::mutate(claim_id, |maybe_reward| {
+			let total = maybe_reward.get_or_insert_with(Default::default);
+			total.remaining = total.remaining.saturating_add(reward);
+			total.shares = total.shares.saturating_add(shares);
+		});
+		Self::deposit_event(Event::RewardsAllocated(claim_id, reward));
+	}
+
+	/// Takes the share of the reviewer in the reward of the claim out of the reward, and returns
+	/// the amount to pay out.
+	pub(crate) fn take_reward(claim_id: ClaimId, who: &T::AccountId) -> Option<BalanceOf<T>> {
+		let share = RewardShares::<T>::take(claim_id, who)?;
+		let mut reward = ClaimRewards::<T>::get(claim_id)?;
+		// The last reviewer to claim gets whatever is left after rounding.
+		let payout = if u64::from(share) >= reward.shares {
+			reward.remaining
+		} else {
+			multiply_by_rational(
+				reward.remaining.saturated_into(),
+				share.into(),
+				reward.shares.into(),
+			)
+			.map(SaturatedConversion::saturated_into)
+			.unwrap_or_else(|_| Zero::zero())
+		};
+		reward.remaining = reward.remaining.saturating_sub(payout);
+		reward.shares = reward.shares.saturating_sub(share.into());
+		if reward.shares == 0 {
+			ClaimRewards::<T>::remove(claim_id);
+		} else {
+			ClaimRewards::<T>::insert(claim_id, reward);
+		}
+		AllocatedRewards::<T>::mutate(|allocated| *allocated = allocated.saturating_sub(payout));
+		Some(payout)
+	}
+}
+
+/// Deposits the funds into the reward pot, e.g. the slashed deposits of spam submissions.
+impl<T: Config> OnUnbalanced<NegativeImbalanceOf<T>> for Pallet<T> {
+	fn on_nonzero_unbalanced(amount: NegativeImbalanceOf<T>) {
+		T::Currency::resolve_creating(&Self::reward_pot(), amount);
+	}
+}
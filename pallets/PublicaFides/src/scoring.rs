@@ -0,0 +1,66 @@
+//! Credibility scoring of content based on the verdicts on its claims.
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::{Permill, RuntimeDebug};
+
+/// Number of finalized claims of a piece of content, by verdict.
+#[derive(Encode, Decode, Default, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct ClaimTally {
+	/// Claims accepted as true.
+	pub accepted: u32,
+	/// Claims rejected as false.
+	pub rejected: u32,
+	/// Claims rejected as subjective statements.
+	pub subjective: u32,
+}
+
+/// Computes the credibility score of content from the verdicts on its claims.
+pub trait ScoringPolicy {
+	/// Score for content with the given `tally`, or `None` if it can't be scored yet.
+	fn score(tally: &ClaimTally) -> Option<Permill>;
+}
+
+/// Scores content by the ratio of claims accepted as true to all claims decided on veracity.
+/// Subjective claims don't affect the score.
+pub struct AcceptedRatio;
+
+impl ScoringPolicy for AcceptedRatio {
+	fn score(tally: &ClaimTally) -> Option<Permill> {
+		let decided = tally.accepted.saturating_add(tally.rejected);
+		if decided == 0 {
+			return None
+		}
+		Some(Permill::from_rational(tally.accepted, decided))
+	}
+}
+
+/// Rolling credibility of an author or publisher, aggregated from the scores of their content.
+#[derive(Encode, Decode, Default, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct Credibility {
+	/// Number of scored content items.
+	pub scored_content: u32,
+	/// Sum of the scores of the content, in parts per million.
+	pub total_score: u64,
+}
+
+impl Credibility {
+	/// Average score of the content, or `None` if none of it was scored yet.
+	pub fn average(&self) -> Option<Permill> {
+		if self.scored_content == 0 {
+			return None
+		}
+		Some(Permill::from_parts((self.total_score / self.scored_content as u64) as u32))
+	}
+
+	/// Replaces the `old` score of one content item with its `new` score.
+	pub fn rescore(&mut self, old: Option<Permill>, new: Option<Permill>) {
+		if let Some(old) = old {
+			self.scored_content = self.scored_content.saturating_sub(1);
+			self.total_score = self.total_score.saturating_sub(old.deconstruct() as u64);
+		}
+		if let Some(new) = new {
+			self.scored_content = self.scored_content.saturating_add(1);
+			self.total_score = self.total_score.saturating_add(new.deconstruct() as u64);
+		}
+	}
+}
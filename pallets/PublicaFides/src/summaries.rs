@@ -0,0 +1,32 @@
+//! Plain-language summaries of the verdicts on claims, for the media and other lay readers.
+//!
+//! The claim statement in `claim_text_cid` is written for reviewers. Once a claim has its final
+//! verdict, the `PublicSummaryOrigin` can approve a summary of the claim and its verdict without
+//! jargon with `approve_public_summary`, which replaces any summary approved before. The summary
+//! is approved for the verdict the claim had at the time: once the verdict changes, e.g. on appeal
+//! or re-review, the summary is withheld until a summary of the new verdict is approved.
+
+use crate::{ClaimId, ClaimStatus, Claims, Config, Pallet, PublicSummaries};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, BoundedVec};
+use sp_runtime::RuntimeDebug;
+use sp_std::vec::Vec;
+
+/// An approved plain-language summary of a claim.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+pub struct PublicSummary<CidLimit: Get<u32>> {
+	/// The IPFS CID of the summary text.
+	pub summary_cid: BoundedVec<u8, CidLimit>,
+	/// The verdict the summary was approved for.
+	pub verdict: ClaimStatus,
+}
+
+impl<T: Config> Pallet<T> {
+	/// The CID of the approved summary of the claim, unless the verdict on the claim changed
+	/// since the summary was approved.
+	pub fn approved_summary(claim_id: ClaimId) -> Option<Vec<u8>> {
+		let summary = PublicSummaries::<T>::get(claim_id)?;
+		let status = Claims::<T>::get(claim_id)?.status;
+		(status == summary.verdict).then(|| summary.summary_cid.into_inner())
+	}
+}
@@ -1,11 +1,36 @@
 use super::*;
-use crate::{mock::*, Error};
-use frame_support::{assert_noop, assert_ok};
+use crate::{mock::*, reputation::Question, Error};
+use codec::Encode;
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::{DispatchError, DispatchResult},
+	traits::{Get, Hooks},
+	weights::{GetDispatchInfo, Weight},
+};
+use sp_runtime::{
+	traits::{BlakeTwo256, Hash},
+	Permill,
+};
+
+/// Submits content with the given url, without DOI, author, publisher or archived snapshot.
+fn submit_content(who: u64, url: Vec<u8>) -> DispatchResult {
+	PublicaFides::store_content(Origin::signed(who), url, None, None, None, None)
+}
+
+/// Origin of a motion passed by `approvals` out of `seats` Panel members.
+fn panel_origin(approvals: u32, seats: u32) -> Origin {
+	pallet_collective::RawOrigin::<u64, PanelCollective>::Members(approvals, seats).into()
+}
+
+/// Origin of a motion passed by `approvals` out of `seats` Committee members.
+fn committee_origin(approvals: u32, seats: u32) -> Origin {
+	pallet_collective::RawOrigin::<u64, CommitteeCollective>::Members(approvals, seats).into()
+}
 
 #[test]
 fn it_stores_contents() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(PublicaFides::store_content(Origin::signed(1), vec![1, 2], vec![2, 3]));
+		assert_ok!(submit_content(1, vec![1, 2]));
 		assert_eq!(PublicaFides::next_class_id(), 1)
 	});
 }
@@ -13,28 +38,1495 @@ fn it_stores_contents() {
 #[test]
 fn it_stores_claims() {
 	new_test_ext().execute_with(|| {
-		assert_ok!(PublicaFides::store_content(Origin::signed(1), vec![1, 2], vec![2, 3]));
+		assert_ok!(submit_content(1, vec![1, 2]));
+
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+		assert_eq!(PublicaFides::next_claim_id(), 1);
+		let claim = PublicaFides::get_claim(0).unwrap();
+		assert_eq!(claim.claim_text_cid.into_inner(), vec![1, 2]);
+		assert_eq!(claim.status, ClaimStatus::Proposed);
+	});
+}
+
+#[test]
+fn it_fails_if_content_nonexistent() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0),
+			Error::<Test>::NonExistentContent
+		);
+	});
+}
+
+#[test]
+fn it_rejects_claims_without_collective_approval() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+
+		assert_noop!(
+			PublicaFides::store_claim_for_content(Origin::signed(1), vec![1, 2], 0),
+			DispatchError::BadOrigin
+		);
+		// A minority of the Panel is not enough.
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(1, 3), vec![1, 2], 0),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn it_stores_claims_through_panel_motion() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Panel::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+		assert_ok!(submit_content(1, vec![1, 2]));
+
+		let proposal =
+			Call::PublicaFides(crate::Call::store_claim_for_content(vec![1, 2], 0));
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+
+		assert_ok!(Panel::propose(Origin::signed(1), 2, Box::new(proposal), proposal_len));
+		assert_ok!(Panel::vote(Origin::signed(1), hash, 0, true));
+		assert_ok!(Panel::vote(Origin::signed(2), hash, 0, true));
+		assert_ok!(Panel::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+
+		assert_eq!(PublicaFides::next_claim_id(), 1);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::Proposed);
+	});
+}
+
+#[test]
+fn it_runs_objectivity_then_veracity_votes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		// Veracity can't be decided before objectivity.
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), 0),
+			Error::<Test>::InvalidClaimTransition
+		);
+
+		assert_ok!(PublicaFides::approve_claim_objectivity(panel_origin(2, 3), 0));
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::ObjectivityApproved);
+		System::assert_last_event(Event::PublicaFides(crate::Event::ClaimObjectivityApproved(0)));
+
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), 0));
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::VeracityRejected);
+		System::assert_last_event(Event::PublicaFides(crate::Event::ClaimVeracityRejected(0)));
+
+		// The verdict is final.
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), 0),
+			Error::<Test>::InvalidClaimTransition
+		);
+	});
+}
+
+#[test]
+fn it_does_not_vote_on_veracity_of_subjective_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		assert_ok!(PublicaFides::reject_claim_as_subjective(panel_origin(2, 3), 0));
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::RejectedAsSubjective);
+
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), 0),
+			Error::<Test>::InvalidClaimTransition
+		);
+		assert_noop!(
+			PublicaFides::approve_claim_objectivity(panel_origin(2, 3), 0),
+			Error::<Test>::InvalidClaimTransition
+		);
+	});
+}
+
+#[test]
+fn it_requires_the_configured_origin_for_each_phase() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		assert_noop!(
+			PublicaFides::approve_claim_objectivity(committee_origin(2, 3), 0),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PublicaFides::approve_claim_objectivity(panel_origin(2, 3), 0));
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(panel_origin(2, 3), 0),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), 1),
+			Error::<Test>::NonExistentClaim
+		);
+	});
+}
+
+/// Stores a claim for content 0 and moves it through the objectivity vote.
+fn objective_claim(claim_statement: Vec<u8>) -> ClaimId {
+	let claim_id = PublicaFides::next_claim_id();
+	assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), claim_statement, 0));
+	assert_ok!(PublicaFides::approve_claim_objectivity(panel_origin(2, 3), claim_id));
+	claim_id
+}
+
+#[test]
+fn it_scores_content_by_accepted_ratio() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let first = objective_claim(vec![1]);
+		let second = objective_claim(vec![2]);
+		assert_eq!(PublicaFides::get_content(0).score, None);
+
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), first));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::one()));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentScoreUpdated(
+			0,
+			Permill::one(),
+		)));
+
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), second));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::from_percent(50)));
+		assert_eq!(
+			PublicaFides::claim_tally(0),
+			ClaimTally { accepted: 1, rejected: 1, subjective: 0 }
+		);
+	});
+}
+
+#[test]
+fn subjective_claims_do_not_affect_score() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let first = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), first));
+
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![2], 0));
+		assert_ok!(PublicaFides::reject_claim_as_subjective(panel_origin(2, 3), 1));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::one()));
+	});
+}
+
+#[test]
+fn it_deduplicates_claims_by_text() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(1, vec![3, 4]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 1),
+			Error::<Test>::DuplicateClaim
+		);
+		assert_eq!(PublicaFides::find_claim(&[1, 2]), Some(0));
+		assert_eq!(PublicaFides::find_claim(&[5, 6]), None);
+	});
+}
+
+#[test]
+fn it_links_claims_to_more_content() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(1, vec![3, 4]));
+		let claim_id = objective_claim(vec![1, 2]);
+
+		assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, 1));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ClaimLinked(claim_id, 1)));
+		assert_eq!(PublicaFides::get_content(1).claims.into_inner(), vec![claim_id]);
+		assert!(ClaimsToContent::<Test>::contains_key(claim_id, 0));
+		assert!(ClaimsToContent::<Test>::contains_key(claim_id, 1));
+		assert_noop!(
+			PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, 1),
+			Error::<Test>::ClaimAlreadyLinked
+		);
+		assert_noop!(
+			PublicaFides::link_claim_to_content(panel_origin(2, 3), 7, 1),
+			Error::<Test>::NonExistentClaim
+		);
+
+		// The verdict scores every linked content item.
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::one()));
+		assert_eq!(PublicaFides::get_content(1).score, Some(Permill::one()));
+	});
+}
+
+#[test]
+fn linking_a_decided_claim_scores_the_content() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(1, vec![3, 4]));
+		let claim_id = objective_claim(vec![1, 2]);
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), claim_id));
+
+		assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, 1));
+		assert_eq!(PublicaFides::get_content(1).score, Some(Permill::zero()));
+	});
+}
+
+#[test]
+fn it_registers_authors_and_publishers() {
+	new_test_ext().execute_with(|| {
+		// Accounts can't link themselves, or others, to a source.
+		assert_noop!(
+			PublicaFides::register_author(Origin::signed(7), b"Jane Doe".to_vec(), vec![7]),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(PublicaFides::register_author(Origin::root(), b"Jane Doe".to_vec(), vec![7]));
+		System::assert_last_event(Event::PublicaFides(crate::Event::AuthorRegistered(0)));
+		assert_ok!(PublicaFides::register_publisher(Origin::root(), b"Daily".to_vec(), vec![]));
+		System::assert_last_event(Event::PublicaFides(crate::Event::PublisherRegistered(0)));
+		let author = PublicaFides::author(0).unwrap();
+		assert_eq!(author.name.into_inner(), b"Jane Doe".to_vec());
+		assert_eq!(author.accounts.into_inner(), vec![7]);
+
+		assert_ok!(PublicaFides::store_content(
+			Origin::signed(1),
+			vec![1, 2],
+			None,
+			Some(0),
+			Some(0),
+			None
+		));
+		assert_eq!(PublicaFides::get_content(0).author, Some(0));
+		assert_eq!(PublicaFides::get_content(0).publisher, Some(0));
+
+		assert_noop!(
+			PublicaFides::store_content(Origin::signed(1), vec![1, 2], None, Some(1), None, None),
+			Error::<Test>::NonExistentAuthor
+		);
+		assert_noop!(
+			PublicaFides::store_content(Origin::signed(1), vec![1, 2], None, None, Some(1), None),
+			Error::<Test>::NonExistentPublisher
+		);
+	});
+}
 
-		assert_ok!(PublicaFides::store_claim_for_content(
+#[test]
+fn it_aggregates_author_and_publisher_credibility() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::register_author(Origin::root(), b"Jane Doe".to_vec(), vec![]));
+		assert_ok!(PublicaFides::register_publisher(Origin::root(), b"Daily".to_vec(), vec![]));
+		assert_ok!(PublicaFides::store_content(
 			Origin::signed(1),
 			vec![1, 2],
+			None,
+			Some(0),
+			Some(0),
+			None
+		));
+		let origin = Origin::signed(1);
+		assert_ok!(PublicaFides::store_content(origin, vec![3, 4], None, None, Some(0), None));
+		assert_eq!(PublicaFides::author_credibility(0).average(), None);
+
+		let first = objective_claim(vec![1]);
+		let second = objective_claim(vec![2]);
+		assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), second, 1));
+
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), first));
+		assert_eq!(PublicaFides::author_credibility(0).average(), Some(Permill::one()));
+		assert_eq!(PublicaFides::publisher_credibility(0).average(), Some(Permill::one()));
+
+		// Content 0 now scores 50% and content 1 scores 0%.
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), second));
+		assert_eq!(
+			PublicaFides::author_credibility(0),
+			Credibility { scored_content: 1, total_score: 500_000 }
+		);
+		assert_eq!(PublicaFides::publisher_credibility(0).average(), Some(Permill::from_percent(25)));
+	});
+}
+
+#[test]
+fn it_reserves_a_deposit_per_submission() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(PublicaFides::content_deposit_for(&[1, 2]), 12);
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_eq!(Balances::reserved_balance(1), 12);
+		assert_eq!(PublicaFides::content_deposit(0), Some((1, 12)));
+
+		// Account 3 can't afford the deposit.
+		assert_noop!(
+			submit_content(3, vec![3, 4]),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn it_returns_the_deposit_when_review_completes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_noop!(
+			PublicaFides::complete_review(Origin::signed(1), 0),
+			DispatchError::BadOrigin
+		);
+
+		assert_ok!(PublicaFides::complete_review(Origin::root(), 0));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentReviewCompleted(0)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		assert_noop!(
+			PublicaFides::complete_review(Origin::root(), 0),
+			Error::<Test>::ReviewConcluded
+		);
+	});
+}
+
+#[test]
+fn it_slashes_the_deposit_of_spam() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(2, vec![3, 4]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![3], 0));
+		assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), 1, 1));
+
+		assert_ok!(PublicaFides::mark_as_spam(Origin::root(), 0));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentMarkedAsSpam(0, 12)));
+		// The claim only raised in the spam is removed, the other one is kept.
+		System::assert_has_event(Event::PublicaFides(crate::Event::ClaimRemoved(0)));
+		assert!(PublicaFides::get_claim(0).is_none());
+		assert_eq!(PublicaFides::find_claim(&[1, 2]), None);
+		assert!(PublicaFides::get_claim(1).is_some());
+		assert!(ClaimsToContent::<Test>::contains_key(1, 1));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 88);
+		// The slashed deposit funds the rewards of reviewers.
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 12);
+		let content = PublicaFides::get_content(0);
+		assert_eq!(content.status, ContentStatus::Rejected);
+		assert!(content.claims.is_empty());
+		assert!(!ClaimsToContent::<Test>::contains_key(0, 0));
+		assert_eq!(PublicaFides::review_ends(0), None);
+		assert_noop!(
+			PublicaFides::mark_as_spam(Origin::root(), 0),
+			Error::<Test>::ReviewConcluded
+		);
+		assert_noop!(
+			PublicaFides::mark_as_spam(Origin::root(), 2),
+			Error::<Test>::NonExistentContent
+		);
+	});
+}
+
+#[test]
+fn it_rejects_input_over_the_bounds() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(submit_content(1, vec![0; 17]), Error::<Test>::UrlTooLong);
+		assert_noop!(
+			PublicaFides::register_author(Origin::root(), vec![0; 9], vec![]),
+			Error::<Test>::CidTooLong
+		);
+		assert_noop!(
+			PublicaFides::register_publisher(Origin::root(), b"Daily".to_vec(), vec![1, 2, 3]),
+			Error::<Test>::TooManySourceAccounts
+		);
+
+		assert_ok!(submit_content(1, vec![0; 16]));
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![0; 9], 0),
+			Error::<Test>::CidTooLong
+		);
+		for claim_statement in 1..=3 {
+			assert_ok!(PublicaFides::store_claim_for_content(
+				panel_origin(2, 3),
+				vec![claim_statement],
+				0
+			));
+		}
+		// Neither the claim nor its text hash is kept when the content is full.
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![4], 0),
+			Error::<Test>::TooManyClaims
+		);
+		assert_eq!(PublicaFides::find_claim(&[4]), None);
+	});
+}
+
+#[test]
+fn it_migrates_to_the_current_storage() {
+	use crate::migrations::v1::{OldClaim, OldContent};
+	use frame_support::{storage::unhashed, traits::StorageVersion};
+
+	new_test_ext().execute_with(|| {
+		StorageVersion::new(0).put::<PublicaFides>();
+		let old_claim =
+			|claim_text_cid: Vec<u8>, is_accepted| OldClaim { claim_text_cid, is_accepted };
+		for (claim_id, claim) in vec![
+			old_claim(vec![1], true),
+			old_claim(vec![2], false),
+			old_claim(vec![0; 9], false),
+			old_claim(vec![3], false),
+			old_claim(vec![4], true),
+			old_claim(vec![5], true),
+		]
+		.into_iter()
+		.enumerate()
+		{
+			let claim_id = claim_id as ClaimId;
+			let content_id: u32 = if claim_id == 4 { 1 } else { 0 };
+			let key = ClaimsToContent::<Test>::hashed_key_for(claim_id, content_id);
+			unhashed::put(&key, &claim);
+		}
+		let old_content = |url, claims| OldContent { url, claims };
+		for (content_id, content) in vec![
+			old_content(vec![1, 2], vec![0, 1, 2, 3, 5]),
+			old_content(vec![0; 17], vec![4]),
+			old_content(vec![3, 4], vec![]),
+		]
+		.into_iter()
+		.enumerate()
+		{
+			let key = ContentStorage::<Test>::hashed_key_for(content_id as u32);
+			unhashed::put(&key, &content);
+		}
+
+		crate::migrations::v1::migrate::<Test>();
+		assert_eq!(StorageVersion::get::<PublicaFides>(), StorageVersion::new(1));
+
+		// Claims are moved out of their links with their verdict.
+		let claim = PublicaFides::get_claim(1).unwrap();
+		assert_eq!(claim.claim_text_cid.into_inner(), vec![2]);
+		assert_eq!(claim.status, ClaimStatus::VeracityRejected);
+		assert_eq!(ClaimsToContent::<Test>::get(1, 0), Some(()));
+		assert_eq!(PublicaFides::verdict_history(1).len(), 1);
+		assert_eq!(PublicaFides::find_claim(&[1]), Some(0));
+
+		// The claim with an oversized CID is dropped with its links.
+		assert!(PublicaFides::get_claim(2).is_none());
+		assert_eq!(PublicaFides::find_claim(&[0; 9]), None);
+		assert!(!ClaimsToContent::<Test>::contains_key(2, 0));
+
+		// Content 0 keeps three claims and is scored. The claim that doesn't fit was only raised
+		// there, so it is removed.
+		let content = PublicaFides::get_content(0);
+		assert_eq!(content.claims.into_inner(), vec![0, 1, 3]);
+		assert_eq!(content.score, Some(Permill::from_rational(1u32, 3)));
+		assert_eq!(content.author, None);
+		assert_eq!(content.status, ContentStatus::Scored);
+		assert_eq!((content.doi, content.archive_cid), (None, None));
+		assert_eq!(PublicaFides::find_content(&[1, 2], None), Some(0));
+		assert!(PublicaFides::get_claim(5).is_none());
+		assert!(!ClaimsToContent::<Test>::contains_key(5, 0));
+
+		// Content 1 has an oversized url, so it's dropped along with its only claim.
+		assert!(!ContentStorage::<Test>::contains_key(1));
+		assert!(!ClaimsToContent::<Test>::contains_key(4, 1));
+		assert!(PublicaFides::get_claim(4).is_none());
+
+		// Content without claims isn't scored.
+		let content = PublicaFides::get_content(2);
+		assert_eq!(content.url.into_inner(), vec![3, 4]);
+		assert_eq!(content.score, None);
+		assert_eq!(content.status, ContentStatus::Scored);
+
+		// The searches for expired verdicts and ended reviews start now.
+		assert_eq!(NextReviewBlock::<Test>::get(), System::block_number());
+		assert_eq!(NextContentReviewBlock::<Test>::get(), System::block_number());
+	});
+}
+
+#[test]
+fn it_provides_views_of_content_and_claims() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(1, vec![3, 4]));
+		assert_ok!(submit_content(1, vec![5, 6]));
+		let first = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![2], 0));
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), first));
+		assert_ok!(PublicaFides::mark_as_spam(Origin::root(), 1));
+
+		let accepted =
+			ClaimInfo { id: 0, claim_text_cid: vec![1], status: ClaimStatus::VeracityAccepted };
+		let proposed = ClaimInfo { id: 1, claim_text_cid: vec![2], status: ClaimStatus::Proposed };
+		let content = ContentInfo {
+			id: 0,
+			url: vec![1, 2],
+			claims: vec![accepted.clone(), proposed.clone()],
+			score: Some(Permill::one()),
+			author: None,
+			publisher: None,
+			status: ContentStatus::InReview,
+			doi: None,
+			archive_cid: None,
+		};
+		assert_eq!(PublicaFides::content_info(0), Some(content.clone()));
+		let rejected = PublicaFides::content_info(1).unwrap();
+		assert_eq!(rejected.status, ContentStatus::Rejected);
+		assert!(rejected.claims.is_empty());
+		assert_eq!(PublicaFides::content_score(0), Some(Permill::one()));
+		assert_eq!(PublicaFides::content_score(2), None);
+		assert_eq!(PublicaFides::claims_with_status(ClaimStatus::Proposed, 0, 10), vec![proposed]);
+		assert_eq!(PublicaFides::claims_with_status(ClaimStatus::VeracityRejected, 0, 10), vec![]);
+		// Pages cover a range of claim ids.
+		assert_eq!(
+			PublicaFides::claims_with_status(ClaimStatus::VeracityAccepted, 0, 1),
+			vec![accepted]
+		);
+		assert_eq!(PublicaFides::claims_with_status(ClaimStatus::Proposed, 0, 1), vec![]);
+
+		let page = PublicaFides::content_page(0, 2);
+		assert_eq!(page.iter().map(|c| c.id).collect::<Vec<_>>(), vec![0, 1]);
+		assert_eq!(page[0], content);
+		assert_eq!(PublicaFides::content_page(2, 10).len(), 1);
+		assert!(PublicaFides::content_page(3, 10).is_empty());
+		// Oversized pages are capped rather than refused.
+		assert_eq!(PublicaFides::content_page(0, u32::MAX).len(), 3);
+	});
+}
+
+#[test]
+fn it_bounds_the_content_linked_to_a_claim() {
+	new_test_ext().execute_with(|| {
+		for url in 0..5 {
+			assert_ok!(submit_content(1, vec![url]));
+		}
+		let claim_id = objective_claim(vec![1, 2]);
+		for content_id in 1..4 {
+			assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, content_id));
+		}
+		assert_noop!(
+			PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, 4),
+			Error::<Test>::TooManyContentLinks
+		);
+	});
+}
+
+#[test]
+fn verdicts_refund_the_weight_of_unlinked_content() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(submit_content(1, vec![3, 4]));
+		let claim_id = objective_claim(vec![1, 2]);
+		assert_ok!(PublicaFides::link_claim_to_content(panel_origin(2, 3), claim_id, 1));
+
+		let call = crate::Call::<Test>::accept_claim_veracity(claim_id);
+		assert_eq!(
+			call.get_dispatch_info().weight,
+			<() as WeightInfo>::accept_claim_veracity(4, 4)
+		);
+		let post_info =
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id).unwrap();
+		assert_eq!(post_info.actual_weight, Some(<() as WeightInfo>::accept_claim_veracity(2, 0)));
+	});
+}
+
+/// Passes `proposal` through a motion of `collective`, with the given votes.
+macro_rules! pass_motion {
+	($collective:ident, $proposal:expr, $index:expr, ayes: $ayes:expr, nays: $nays:expr) => {{
+		let proposal = Call::PublicaFides($proposal);
+		let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+		let proposal_weight = proposal.get_dispatch_info().weight;
+		let hash = BlakeTwo256::hash_of(&proposal);
+		let proposer = Origin::signed($ayes[0]);
+		assert_ok!($collective::propose(proposer, 2, Box::new(proposal), proposal_len));
+		for who in $ayes.iter().skip(1) {
+			assert_ok!($collective::vote(Origin::signed(*who), hash, $index, true));
+		}
+		for who in $nays.iter() {
+			assert_ok!($collective::vote(Origin::signed(*who), hash, $index, false));
+		}
+		let closer = Origin::signed(1);
+		assert_ok!($collective::close(closer, hash, $index, proposal_weight, proposal_len));
+	}};
+}
+
+#[test]
+fn reputation_follows_agreement_with_verdicts() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Panel::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+		assert_ok!(Committee::set_members(Origin::root(), vec![4, 5, 6], None, 3));
+		ReviewerReputation::<Test>::insert(3, 10);
+		ReviewerReputation::<Test>::insert(6, 15);
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		pass_motion!(Panel, crate::Call::approve_claim_objectivity(0), 0, ayes: [1, 2], nays: [3]);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::ObjectivityApproved);
+		assert_eq!(
+			ClaimVotes::<Test>::get(0, 3),
+			Some(ClaimVote { objective: Some(false), accepted: None })
+		);
+		// Nothing is settled before the final verdict.
+		assert_eq!(PublicaFides::reputation(1), 0);
+
+		pass_motion!(Committee, crate::Call::accept_claim_veracity(0), 0, ayes: [4, 5], nays: [6]);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::VeracityAccepted);
+		System::assert_has_event(Event::PublicaFides(crate::Event::ReputationSettled(0, 6)));
+		for agreeing in [1, 2, 4, 5] {
+			assert_eq!(PublicaFides::reputation(agreeing), 10);
+		}
+		// Half of the reputation, rounded up, is lost.
+		assert_eq!(PublicaFides::reputation(3), 5);
+		assert_eq!(PublicaFides::reputation(6), 7);
+		assert_eq!(ClaimVotes::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(ClaimVoterCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn votes_against_a_motion_count_for_the_opposite_verdict() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Panel::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		// Voting against rejecting the claim as subjective is voting it objective.
+		pass_motion!(Panel, crate::Call::reject_claim_as_subjective(0), 0, ayes: [1, 2], nays: [3]);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::RejectedAsSubjective);
+		assert_eq!(PublicaFides::reputation(1), 10);
+		assert_eq!(PublicaFides::reputation(2), 10);
+		assert_eq!(PublicaFides::reputation(3), 0);
+	});
+}
+
+#[test]
+fn only_the_deciding_collective_has_its_votes_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Panel::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+		assert_ok!(Committee::set_members(Origin::root(), vec![4, 5, 6], None, 3));
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1, 2], 0));
+
+		// The Committee doesn't decide on objectivity, nor the Panel on veracity.
+		pass_motion!(
+			Committee,
+			crate::Call::approve_claim_objectivity(0),
+			0,
+			ayes: [4, 5],
+			nays: [6]
+		);
+		pass_motion!(Panel, crate::Call::accept_claim_veracity(0), 0, ayes: [1, 2], nays: [3]);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::Proposed);
+		assert_eq!(ClaimVotes::<Test>::iter_prefix(0).count(), 0);
+		assert_eq!(ClaimVoterCount::<Test>::get(0), 0);
+	});
+}
+
+#[test]
+fn it_bounds_the_votes_recorded_per_claim() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1, 2]);
+
+		PublicaFides::record_votes(claim_id, Question::Veracity, true, &[1, 2, 3], &[4, 5, 6]);
+		assert_eq!(ClaimVoterCount::<Test>::get(claim_id), 4);
+		assert_eq!(ClaimVotes::<Test>::get(claim_id, 5), None);
+		// Voters who are already recorded can still vote on other questions.
+		PublicaFides::record_votes(claim_id, Question::Objectivity, true, &[1, 6], &[]);
+		assert_eq!(
+			ClaimVotes::<Test>::get(claim_id, 1),
+			Some(ClaimVote { objective: Some(true), accepted: Some(true) })
+		);
+		assert_eq!(ClaimVotes::<Test>::get(claim_id, 6), None);
+
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::reputation(4), 10);
+		assert_eq!(PublicaFides::reputation(1), 0);
+
+		// Votes on claims with a verdict are not recorded.
+		PublicaFides::record_votes(claim_id, Question::Veracity, true, &[1], &[]);
+		assert_eq!(ClaimVotes::<Test>::iter_prefix(claim_id).count(), 0);
+	});
+}
+
+#[test]
+fn claim_votes_agree_with_verdicts() {
+	use ClaimStatus::*;
+	let agrees =
+		|objective, accepted, status| ClaimVote { objective, accepted }.agrees_with(status);
+	assert_eq!(agrees(Some(true), None, ObjectivityApproved), None);
+	assert_eq!(agrees(None, None, VeracityAccepted), None);
+	assert_eq!(agrees(Some(false), None, RejectedAsSubjective), Some(true));
+	assert_eq!(agrees(None, Some(true), RejectedAsSubjective), None);
+	assert_eq!(agrees(Some(true), Some(true), VeracityAccepted), Some(true));
+	assert_eq!(agrees(Some(true), Some(false), VeracityAccepted), Some(false));
+	assert_eq!(agrees(Some(false), Some(false), VeracityRejected), Some(false));
+	assert_eq!(agrees(None, Some(false), VeracityRejected), Some(true));
+}
+
+#[test]
+fn reputation_weighs_votes() {
+	use pallet_collective::VoteWeight;
+
+	new_test_ext().execute_with(|| {
+		assert_eq!(PublicaFides::vote_weight(&1), 1);
+		ReviewerReputation::<Test>::insert(1, 9);
+		assert_eq!(PublicaFides::vote_weight(&1), 1);
+		ReviewerReputation::<Test>::insert(1, 25);
+		assert_eq!(PublicaFides::vote_weight(&1), 3);
+	});
+}
+
+#[test]
+fn rewards_are_shared_by_reputation() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&PublicaFides::reward_pot(), 31);
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1, 2]);
+		ReviewerReputation::<Test>::insert(1, 10);
+		ReviewerReputation::<Test>::insert(3, 30);
+		PublicaFides::record_votes(claim_id, Question::Veracity, true, &[1, 2], &[3]);
+
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		System::assert_has_event(Event::PublicaFides(crate::Event::RewardsAllocated(claim_id, 20)));
+		assert_eq!(crate::AllocatedRewards::<Test>::get(), 20);
+		assert_eq!(PublicaFides::unallocated_rewards(), 10);
+		// Shares of 20, 10 and 15 in a reward of 20.
+		assert_eq!(PublicaFides::reward_share(claim_id, 1), Some(20));
+
+		let paid = |who: u64| {
+			let before = Balances::free_balance(who);
+			assert_ok!(PublicaFides::claim_reward(Origin::signed(who), claim_id));
+			Balances::free_balance(who) - before
+		};
+		assert_eq!(paid(1), 8);
+		System::assert_last_event(Event::PublicaFides(crate::Event::RewardPaid(claim_id, 1, 8)));
+		assert_eq!(paid(2), 4);
+		// The last one gets what is left after rounding.
+		assert_eq!(paid(3), 8);
+		assert_eq!(PublicaFides::claim_reward_of(claim_id), None);
+		assert_eq!(crate::AllocatedRewards::<Test>::get(), 0);
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 11);
+
+		assert_noop!(
+			PublicaFides::claim_reward(Origin::signed(1), claim_id),
+			Error::<Test>::NoReward
+		);
+		assert_noop!(
+			PublicaFides::claim_reward(Origin::signed(4), claim_id),
+			Error::<Test>::NoReward
+		);
+	});
+}
+
+#[test]
+fn rewards_are_limited_by_the_pot() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&PublicaFides::reward_pot(), 11);
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let first = objective_claim(vec![1]);
+		let second = objective_claim(vec![2]);
+		PublicaFides::record_votes(first, Question::Veracity, true, &[1], &[]);
+		PublicaFides::record_votes(second, Question::Veracity, true, &[2], &[]);
+
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), first));
+		assert_eq!(PublicaFides::claim_reward_of(first).map(|reward| reward.remaining), Some(10));
+		// The rest of the pot is kept to keep the pot alive.
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), second));
+		assert_eq!(PublicaFides::claim_reward_of(second), None);
+		assert_eq!(PublicaFides::reward_share(second, 2), None);
+		assert_eq!(PublicaFides::reputation(2), 10);
+
+		assert_ok!(PublicaFides::claim_reward(Origin::signed(1), first));
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 1);
+	});
+}
+
+#[test]
+fn reviewers_without_reputation_get_no_reward() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&PublicaFides::reward_pot(), 100);
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1, 2]);
+		PublicaFides::record_votes(claim_id, Question::Veracity, true, &[], &[1]);
+
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::claim_reward_of(claim_id), None);
+		assert_eq!(PublicaFides::unallocated_rewards(), 99);
+	});
+}
+
+#[test]
+fn inflation_funds_the_reward_pot() {
+	new_test_ext().execute_with(|| {
+		let weight = PublicaFides::on_initialize(2);
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 0);
+
+		set_reward_inflation(5);
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		assert_eq!(PublicaFides::on_initialize(3), weight + db_weight.reads_writes(1, 1));
+		PublicaFides::on_initialize(4);
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 10);
+		assert_eq!(Balances::total_issuance(), 215);
+	});
+}
+
+#[test]
+fn upheld_appeals_change_the_verdict_and_return_the_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::one()));
+
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_eq!(Balances::reserved_balance(2), 30);
+		assert_eq!(PublicaFides::get_claim(claim_id).unwrap().status, ClaimStatus::UnderAppeal);
+		// The claim doesn't count towards the score while it is under appeal.
+		assert_eq!(PublicaFides::get_content(0).score, None);
+
+		System::set_block_number(5);
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityRejected
+		));
+		System::assert_last_event(Event::PublicaFides(crate::Event::AppealUpheld(
+			claim_id,
+			ClaimStatus::VeracityRejected,
+		)));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 100);
+		let status = PublicaFides::get_claim(claim_id).map(|claim| claim.status);
+		assert_eq!(status, Some(ClaimStatus::VeracityRejected));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::zero()));
+		assert_eq!(PublicaFides::appeal(claim_id), None);
+		assert_eq!(
+			PublicaFides::verdict_history(claim_id),
+			vec![
+				Verdict { status: ClaimStatus::VeracityAccepted, decided_at: 1, on_appeal: false },
+				Verdict { status: ClaimStatus::VeracityRejected, decided_at: 5, on_appeal: true },
+			]
+		);
+	});
+}
+
+#[test]
+fn dismissed_appeals_slash_the_bond() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1], 0));
+		assert_ok!(PublicaFides::reject_claim_as_subjective(panel_origin(2, 3), 0));
+
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), 0));
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
 			0,
-			false
+			ClaimStatus::RejectedAsSubjective
 		));
-		assert_eq!(PublicaFides::next_claim_id(), 1);
+		System::assert_last_event(Event::PublicaFides(crate::Event::AppealDismissed(0, 30)));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 70);
+		assert_eq!(Balances::free_balance(PublicaFides::reward_pot()), 30);
+		assert_eq!(PublicaFides::get_claim(0).unwrap().status, ClaimStatus::RejectedAsSubjective);
+		assert_eq!(PublicaFides::verdict_history(0).len(), 2);
+	});
+}
+
+#[test]
+fn appeals_rescore_content_whose_review_is_closed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::register_author(Origin::root(), b"Jane Doe".to_vec(), vec![]));
+		let origin = Origin::signed(1);
+		assert_ok!(PublicaFides::store_content(origin, vec![1, 2], None, Some(0), None, None));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_ok!(PublicaFides::complete_review(Origin::root(), 0));
+		assert_eq!(PublicaFides::get_content(0).status, ContentStatus::Scored);
+		assert_eq!(PublicaFides::author_credibility(0).average(), Some(Permill::one()));
+
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityRejected
+		));
+		System::assert_has_event(Event::PublicaFides(crate::Event::ContentScoreUpdated(
+			0,
+			Permill::zero(),
+		)));
+		let content = PublicaFides::get_content(0);
+		assert_eq!(content.status, ContentStatus::Scored);
+		assert_eq!(content.score, Some(Permill::zero()));
+		assert_eq!(PublicaFides::author_credibility(0).average(), Some(Permill::zero()));
+	});
+}
+
+#[test]
+fn only_final_verdicts_can_be_appealed_a_limited_number_of_times() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1]);
+		assert_noop!(
+			PublicaFides::appeal_claim(Origin::signed(2), claim_id),
+			Error::<Test>::InvalidClaimTransition
+		);
+		assert_noop!(
+			PublicaFides::decide_appeal(Origin::root(), claim_id, ClaimStatus::VeracityAccepted),
+			Error::<Test>::InvalidClaimTransition
+		);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_noop!(
+			PublicaFides::appeal_claim(Origin::signed(3), claim_id),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_noop!(
+			PublicaFides::appeal_claim(Origin::signed(1), claim_id),
+			Error::<Test>::InvalidClaimTransition
+		);
+		assert_noop!(
+			PublicaFides::decide_appeal(Origin::signed(2), claim_id, ClaimStatus::VeracityRejected),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PublicaFides::decide_appeal(Origin::root(), claim_id, ClaimStatus::UnderAppeal),
+			Error::<Test>::InvalidVerdict
+		);
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityRejected
+		));
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityAccepted
+		));
+		// The first verdict and two appeals fill the review.
+		assert_noop!(
+			PublicaFides::appeal_claim(Origin::signed(2), claim_id),
+			Error::<Test>::TooManyAppeals
+		);
+	});
+}
+
+/// Runs the `on_initialize` hook of the pallet in every block up to block `n`.
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		PublicaFides::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn expired_verdicts_are_reviewed_again() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::set_review_period(Origin::root(), 1, Some(10)));
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::set_claim_category(panel_origin(2, 3), claim_id, 1));
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::review_due(claim_id), Some(11));
+
+		run_to_block(10);
+		let status = PublicaFides::get_claim(claim_id).map(|claim| claim.status);
+		assert_eq!(status, Some(ClaimStatus::VeracityAccepted));
+		run_to_block(11);
+		System::assert_last_event(Event::PublicaFides(crate::Event::ClaimPendingReReview(
+			claim_id,
+			ClaimStatus::VeracityAccepted,
+		)));
+		assert_eq!(PublicaFides::get_claim(claim_id).unwrap().status, ClaimStatus::PendingReReview);
+		assert_eq!(PublicaFides::review_due(claim_id), None);
+		// The claim doesn't count towards the score until it is reviewed again.
+		assert_eq!(PublicaFides::get_content(0).score, None);
+
+		// The claim is reviewed again from the objectivity vote on.
+		System::set_block_number(12);
+		assert_noop!(
+			PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id),
+			Error::<Test>::InvalidClaimTransition
+		);
+		assert_ok!(PublicaFides::approve_claim_objectivity(panel_origin(2, 3), claim_id));
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::zero()));
+		assert_eq!(PublicaFides::review_due(claim_id), Some(22));
+		assert_eq!(PublicaFides::verdict_history(claim_id).len(), 2);
+		// The new verdict starts a new review, so it can be appealed again.
+		assert_eq!(PublicaFides::verdicts_in_review(claim_id), 1);
+	});
+}
+
+#[test]
+fn verdict_history_keeps_every_verdict() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::set_review_period(Origin::root(), 0, Some(5)));
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		for status in [ClaimStatus::VeracityRejected, ClaimStatus::VeracityAccepted] {
+			assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+			assert_ok!(PublicaFides::decide_appeal(Origin::root(), claim_id, status));
+		}
+		assert_eq!(PublicaFides::verdicts_in_review(claim_id), 3);
+
+		// The re-review adds a fourth verdict, more than `MaxVerdicts`, and keeps the others.
+		run_to_block(6);
+		assert_ok!(PublicaFides::approve_claim_objectivity(panel_origin(2, 3), claim_id));
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), claim_id));
+		let history = PublicaFides::verdict_history(claim_id);
+		assert_eq!(history.len(), 4);
 		assert_eq!(
-			PublicaFides::get_claims(0, 0),
-			Claim { claim_text_cid: [1, 2].to_vec(), is_accepted: true }
+			history[0],
+			Verdict { status: ClaimStatus::VeracityAccepted, decided_at: 1, on_appeal: false }
 		);
+		assert_eq!(PublicaFides::last_verdict(claim_id), Some(history[3]));
+		assert_eq!(PublicaFides::verdicts_in_review(claim_id), 1);
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
 	});
 }
 
 #[test]
-fn it_fails_if_content_nonexistent() {
+fn expired_verdicts_are_moved_in_bounded_batches() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::set_review_period(Origin::root(), 0, Some(5)));
+		assert_ok!(submit_content(1, vec![1, 2]));
+		for claim_id in 0..3 {
+			objective_claim(vec![claim_id as u8]);
+			assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		}
+
+		let pending = || {
+			(0..3)
+				.filter(|claim_id| {
+					PublicaFides::get_claim(claim_id).unwrap().status ==
+						ClaimStatus::PendingReReview
+				})
+				.count()
+		};
+		run_to_block(6);
+		assert_eq!(pending(), 2);
+		run_to_block(7);
+		assert_eq!(pending(), 3);
+		assert_eq!(NextReviewBlock::<Test>::get(), 7);
+	});
+}
+
+#[test]
+fn review_periods_follow_categories_and_appeals() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			PublicaFides::set_review_period(Origin::signed(1), 1, Some(10)),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PublicaFides::set_review_period(Origin::root(), 1, Some(0)),
+			Error::<Test>::ZeroReviewPeriod
+		);
+		assert_noop!(
+			PublicaFides::set_claim_category(panel_origin(2, 3), 0, 1),
+			Error::<Test>::NonExistentClaim
+		);
+		assert_ok!(PublicaFides::set_review_period(Origin::root(), 1, Some(10)));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ReviewPeriodSet(1, Some(10))));
+
+		// Verdicts in categories without a review period stand for good.
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_eq!(PublicaFides::review_due(claim_id), None);
+
+		// Moving the claim to a category with a review period schedules the re-review.
+		System::set_block_number(4);
+		assert_ok!(PublicaFides::set_claim_category(panel_origin(2, 3), claim_id, 1));
+		assert_eq!(PublicaFides::claim_category(claim_id), 1);
+		assert_eq!(PublicaFides::review_due(claim_id), Some(11));
+
+		// The verdict on appeal isn't re-reviewed, the verdict given on appeal is.
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_eq!(PublicaFides::review_due(claim_id), None);
+		assert!(!ReviewQueue::<Test>::contains_key(11, claim_id));
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityRejected
+		));
+		assert_eq!(PublicaFides::review_due(claim_id), Some(14));
+
+		assert_ok!(PublicaFides::set_review_period(Origin::root(), 1, None));
+		assert_ok!(PublicaFides::set_claim_category(panel_origin(2, 3), claim_id, 1));
+		assert_eq!(PublicaFides::review_due(claim_id), None);
+	});
+}
+
+#[test]
+fn content_is_scored_once_its_review_window_ends() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_eq!(PublicaFides::get_content(0).status, ContentStatus::Submitted);
+		assert_eq!(PublicaFides::review_ends(0), None);
+
+		let claim_id = objective_claim(vec![1]);
+		System::assert_has_event(Event::PublicaFides(crate::Event::ContentReviewStarted(0, 11)));
+		assert_eq!(PublicaFides::get_content(0).status, ContentStatus::InReview);
+		assert_eq!(PublicaFides::review_ends(0), Some(11));
+		// More claims don't extend the review window.
+		System::set_block_number(5);
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![2], 0));
+		assert_eq!(PublicaFides::review_ends(0), Some(11));
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+
+		PublicaFides::on_idle(10, Weight::max_value());
+		assert_eq!(PublicaFides::get_content(0).status, ContentStatus::InReview);
+		PublicaFides::on_idle(11, Weight::max_value());
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentReviewCompleted(0)));
+		let content = PublicaFides::get_content(0);
+		assert_eq!(content.status, ContentStatus::Scored);
+		assert_eq!(content.score, Some(Permill::one()));
+		assert_eq!(PublicaFides::review_ends(0), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		// The review is closed for good.
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![3], 0),
+			Error::<Test>::ReviewConcluded
+		);
+		assert_noop!(
+			PublicaFides::complete_review(Origin::root(), 0),
+			Error::<Test>::ReviewConcluded
+		);
+		assert_noop!(PublicaFides::mark_as_spam(Origin::root(), 0), Error::<Test>::ReviewConcluded);
+
+		// Later verdicts still rescore the content, which stays scored.
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), claim_id));
+		assert_eq!(PublicaFides::get_content(0).score, None);
+		assert_ok!(PublicaFides::decide_appeal(
+			Origin::root(),
+			claim_id,
+			ClaimStatus::VeracityRejected
+		));
+		System::assert_has_event(Event::PublicaFides(crate::Event::ContentScoreUpdated(
+			0,
+			Permill::zero(),
+		)));
+		let content = PublicaFides::get_content(0);
+		assert_eq!(content.status, ContentStatus::Scored);
+		assert_eq!(content.score, Some(Permill::zero()));
+	});
+}
+
+#[test]
+fn reviews_are_closed_within_the_idle_weight() {
+	new_test_ext().execute_with(|| {
+		for content_id in 0..2 {
+			let url = vec![1, content_id as u8];
+			assert_ok!(submit_content(1, url));
+			assert_ok!(PublicaFides::store_claim_for_content(
+				panel_origin(2, 3),
+				vec![content_id as u8],
+				content_id
+			));
+		}
+		let status = |content_id| PublicaFides::get_content(content_id).status;
+
+		assert_eq!(PublicaFides::on_idle(11, 0), 0);
+		assert_eq!(status(0), ContentStatus::InReview);
+		let weight = <() as WeightInfo>::close_reviews(1);
+		assert_eq!(PublicaFides::on_idle(11, weight), weight);
+		let scored = (0..2).filter(|content_id| status(*content_id) == ContentStatus::Scored);
+		assert_eq!(scored.count(), 1);
+		PublicaFides::on_idle(12, Weight::max_value());
+		assert_eq!((status(0), status(1)), (ContentStatus::Scored, ContentStatus::Scored));
+		// Content without claims isn't reviewed and stays submitted.
+		assert_ok!(submit_content(1, vec![3, 4]));
+		PublicaFides::on_idle(30, Weight::max_value());
+		assert_eq!(status(2), ContentStatus::Submitted);
+	});
+}
+
+#[test]
+fn reviews_which_cannot_be_closed_leave_the_queue() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		objective_claim(vec![1]);
+		assert_ok!(PublicaFides::complete_review(Origin::root(), 0));
+		// An entry left behind for content whose review is already closed.
+		ContentReviewQueue::<Test>::insert(11, 0, ());
+		assert_ok!(submit_content(1, vec![3, 4]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![2], 1));
+
+		PublicaFides::on_idle(11, Weight::max_value());
+		assert_eq!(PublicaFides::get_content(1).status, ContentStatus::Scored);
+		assert_eq!(ContentReviewQueue::<Test>::iter_prefix(11).count(), 0);
+		assert_eq!(NextContentReviewBlock::<Test>::get(), 12);
+	});
+}
+
+#[test]
+fn submitters_can_withdraw_content_before_its_review_starts() {
 	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		assert_eq!(PublicaFides::get_content(0).submitter, 1);
+		assert_noop!(
+			PublicaFides::withdraw_content(Origin::signed(2), 0),
+			Error::<Test>::NotSubmitter
+		);
 		assert_noop!(
-			PublicaFides::store_claim_for_content(Origin::signed(1), vec![1, 2], 0, false),
+			PublicaFides::withdraw_content(Origin::signed(1), 1),
 			Error::<Test>::NonExistentContent
 		);
+
+		assert_ok!(PublicaFides::withdraw_content(Origin::signed(1), 0));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentWithdrawn(0)));
+		assert_eq!(PublicaFides::get_content(0).status, ContentStatus::Withdrawn);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_noop!(
+			PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1], 0),
+			Error::<Test>::ReviewConcluded
+		);
+		assert_noop!(
+			PublicaFides::withdraw_content(Origin::signed(1), 0),
+			Error::<Test>::ReviewConcluded
+		);
+
+		// Once a claim is raised, the content is under review and can't be withdrawn anymore.
+		assert_ok!(submit_content(1, vec![3, 4]));
+		assert_ok!(PublicaFides::store_claim_for_content(panel_origin(2, 3), vec![1], 1));
+		assert_noop!(
+			PublicaFides::withdraw_content(Origin::signed(1), 1),
+			Error::<Test>::ReviewStarted
+		);
+	});
+}
+
+#[test]
+fn corrections_carry_the_claims_of_the_original() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let accepted = objective_claim(vec![1]);
+		let pending = objective_claim(vec![2]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), accepted));
+		assert_ok!(submit_content(1, vec![1, 3]));
+		assert_noop!(
+			PublicaFides::link_correction(Origin::signed(1), 0, 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PublicaFides::link_correction(panel_origin(2, 3), 0, 0),
+			Error::<Test>::InvalidCorrection
+		);
+		assert_noop!(
+			PublicaFides::link_correction(panel_origin(2, 3), 0, 2),
+			Error::<Test>::NonExistentContent
+		);
+
+		assert_ok!(PublicaFides::link_correction(panel_origin(2, 3), 0, 1));
+		System::assert_has_event(Event::PublicaFides(crate::Event::ContentReviewStarted(1, 11)));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentCorrected(0, 1, 2)));
+		assert_eq!(PublicaFides::correction_of(0), Some(1));
+		let correction = PublicaFides::get_content(1);
+		assert_eq!(correction.claims.into_inner(), vec![accepted, pending]);
+		assert_eq!(correction.status, ContentStatus::InReview);
+		// The verdict carries over, the pending claim is evaluated for both versions.
+		assert_eq!(correction.score, Some(Permill::one()));
+		assert_ok!(PublicaFides::reject_claim_veracity(committee_origin(2, 3), pending));
+		assert_eq!(PublicaFides::get_content(1).score, Some(Permill::from_percent(50)));
+		assert_eq!(PublicaFides::get_content(0).score, Some(Permill::from_percent(50)));
+
+		assert_ok!(submit_content(1, vec![1, 4]));
+		assert_noop!(
+			PublicaFides::link_correction(panel_origin(2, 3), 0, 2),
+			Error::<Test>::AlreadyCorrected
+		);
+		// Withdrawn content can be neither corrected nor a correction.
+		assert_ok!(PublicaFides::withdraw_content(Origin::signed(1), 2));
+		assert_noop!(
+			PublicaFides::link_correction(panel_origin(2, 3), 1, 2),
+			Error::<Test>::ReviewConcluded
+		);
+		assert_noop!(
+			PublicaFides::link_correction(panel_origin(2, 3), 2, 1),
+			Error::<Test>::InvalidCorrection
+		);
+	});
+}
+
+#[test]
+fn corrections_may_keep_the_url_of_the_original() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(PublicaFides::register_author(Origin::root(), b"Jane Doe".to_vec(), vec![]));
+		let url = b"a.io/n".to_vec();
+		assert_ok!(PublicaFides::store_content(
+			Origin::signed(1),
+			url.clone(),
+			None,
+			Some(0),
+			None,
+			Some(vec![1])
+		));
+		let claim_id = objective_claim(vec![1]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), claim_id));
+		assert_ok!(PublicaFides::complete_review(Origin::root(), 0));
+		// Only a declared correction may have the url of the original.
+		assert_noop!(submit_content(1, url.clone()), Error::<Test>::DuplicateContent);
+		assert_noop!(
+			PublicaFides::store_correction(Origin::signed(1), 1, url.clone(), None, None),
+			Error::<Test>::NonExistentContent
+		);
+		// The snapshot of the original is no correction.
+		assert_noop!(
+			PublicaFides::store_correction(Origin::signed(1), 0, url.clone(), None, Some(vec![1])),
+			Error::<Test>::DuplicateContent
+		);
+
+		let doi = Some(b"10.1000/182".to_vec());
+		assert_ok!(PublicaFides::store_correction(
+			Origin::signed(1),
+			0,
+			url.clone(),
+			doi.clone(),
+			Some(vec![1, 3])
+		));
+		System::assert_last_event(Event::PublicaFides(crate::Event::ContentStored(1)));
+		let correction = PublicaFides::get_content(1);
+		assert_eq!((correction.url.to_vec(), correction.author), (url.clone(), Some(0)));
+		assert_eq!(correction.doi.map(|doi| doi.to_vec()), doi);
+		// The url keeps finding the original.
+		assert_eq!(PublicaFides::find_content(&url, None), Some(0));
+		assert_eq!(PublicaFides::find_content(b"b.io", Some(&[1, 3])), Some(1));
+
+		assert_ok!(PublicaFides::link_correction(panel_origin(2, 3), 0, 1));
+		assert_eq!(PublicaFides::get_content(1).score, Some(Permill::one()));
+		assert_noop!(
+			PublicaFides::store_correction(Origin::signed(1), 0, url, None, None),
+			Error::<Test>::AlreadyCorrected
+		);
+	});
+}
+
+#[test]
+fn links_to_the_same_article_are_normalised_alike() {
+	use crate::fingerprint::normalise_url;
+
+	let normalised = b"example.com/news?id=1".to_vec();
+	for url in [
+		&b"example.com/news?id=1"[..],
+		b"https://example.com/news?id=1",
+		b"HTTP://WWW.Example.com:80/news/?id=1#comments",
+		b" https://example.com:443/news//?utm_source=feed&id=1&fbclid=abc \n",
+	]
+	.iter()
+	{
+		assert_eq!(normalise_url(url), normalised);
+	}
+	assert_eq!(normalise_url(b"example.com/?b=2&a=1&"), b"example.com?a=1&b=2".to_vec());
+	// Paths are case sensitive.
+	assert_ne!(normalise_url(b"example.com/News"), normalise_url(b"example.com/news"));
+}
+
+#[test]
+fn it_rejects_duplicate_content() {
+	new_test_ext().execute_with(|| {
+		let store = |who, url: &[u8], archive_cid| {
+			let origin = Origin::signed(who);
+			PublicaFides::store_content(origin, url.to_vec(), None, None, None, archive_cid)
+		};
+		assert_ok!(store(1, b"a.io/n", None));
+		assert_eq!(PublicaFides::find_content(b"https://www.a.io/n/", None), Some(0));
+		assert_noop!(store(2, b"HTTP://A.io/n/", None), Error::<Test>::DuplicateContent);
+		// A snapshot doesn't make the same url another article.
+		assert_noop!(store(2, b"a.io/n", Some(vec![1, 2])), Error::<Test>::DuplicateContent);
+
+		// Nor does another url make the same snapshot another article.
+		assert_ok!(store(1, b"b.io/n", Some(vec![1, 2])));
+		assert_eq!(PublicaFides::find_content(b"c.io", Some(&[1, 2])), Some(1));
+		assert_noop!(store(2, b"c.io", Some(vec![1, 2])), Error::<Test>::DuplicateContent);
+		assert_noop!(store(1, b"c.io", Some(vec![0; 9])), Error::<Test>::CidTooLong);
+
+		// Withdrawn content can be submitted again.
+		assert_ok!(PublicaFides::withdraw_content(Origin::signed(1), 1));
+		assert_eq!(PublicaFides::find_content(b"b.io/n", None), None);
+		assert_eq!(PublicaFides::find_content(b"c.io", Some(&[1, 2])), None);
+		assert_ok!(store(2, b"c.io", Some(vec![1, 2])));
+		assert_eq!(PublicaFides::find_content(b"c.io", Some(&[1, 2])), Some(2));
+	});
+}
+
+#[test]
+fn dois_are_validated() {
+	let valid = [&b"10.1000/182"[..], b"10.1016.12/j.cell.2019.01.001", b"10.1002/(SICI)1097-4571"];
+	for doi in valid.iter() {
+		assert!(crate::doi::is_valid(doi));
+	}
+	for doi in [
+		&b"10.1000"[..],
+		b"10.1000/",
+		b"11.1000/182",
+		b"10./182",
+		b"10.1a/182",
+		b"10.1000/18 2",
+		b"doi:10.1000/182",
+		b"https://doi.org/10.1000/182",
+	]
+	.iter()
+	{
+		assert!(!crate::doi::is_valid(doi));
+	}
+}
+
+#[test]
+fn it_stores_the_doi_and_archived_snapshot_of_content() {
+	new_test_ext().execute_with(|| {
+		let store = |url: &[u8], doi: &[u8], archive_cid| {
+			let (origin, url, doi) = (Origin::signed(1), url.to_vec(), Some(doi.to_vec()));
+			PublicaFides::store_content(origin, url, doi, None, None, archive_cid)
+		};
+		assert_ok!(store(b"a.io/n", b"10.1000/182", Some(vec![7, 7])));
+		let content = PublicaFides::content_info(0).unwrap();
+		assert_eq!(content.doi, Some(b"10.1000/182".to_vec()));
+		assert_eq!(content.archive_cid, Some(vec![7, 7]));
+
+		assert_noop!(store(b"b.io", b"doi:10.1000/1", None), Error::<Test>::InvalidDoi);
+		// DOIs have a bound of their own, shorter than the one of urls.
+		assert_noop!(store(b"b.io", b"10.1000/aaaaa", None), Error::<Test>::DoiTooLong);
+		assert_noop!(store(&[b'a'; 17], b"10.1000/1", None), Error::<Test>::UrlTooLong);
+		assert_noop!(store(b"b.io", b"10.1000/1", Some(vec![0; 9])), Error::<Test>::CidTooLong);
+	});
+}
+
+#[test]
+fn public_summaries_follow_the_verdict_they_were_approved_for() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(submit_content(1, vec![1, 2]));
+		let accepted = objective_claim(vec![1]);
+		let pending = objective_claim(vec![2]);
+		assert_ok!(PublicaFides::accept_claim_veracity(committee_origin(2, 3), accepted));
+		assert_noop!(
+			PublicaFides::approve_public_summary(panel_origin(2, 3), accepted, vec![9]),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			PublicaFides::approve_public_summary(committee_origin(2, 3), pending, vec![9]),
+			Error::<Test>::NoFinalVerdict
+		);
+		assert_noop!(
+			PublicaFides::approve_public_summary(committee_origin(2, 3), accepted, vec![0; 9]),
+			Error::<Test>::CidTooLong
+		);
+
+		assert_ok!(PublicaFides::approve_public_summary(committee_origin(2, 3), accepted, vec![9]));
+		System::assert_last_event(Event::PublicaFides(crate::Event::PublicSummaryApproved(
+			accepted,
+			ClaimStatus::VeracityAccepted,
+		)));
+		// The technical claim statement is left as it is.
+		assert_eq!(PublicaFides::get_claim(accepted).unwrap().claim_text_cid.into_inner(), vec![1]);
+		let summary = ClaimSummary {
+			id: accepted,
+			status: ClaimStatus::VeracityAccepted,
+			summary_cid: vec![9],
+		};
+		assert_eq!(PublicaFides::public_summaries(0), vec![summary]);
+
+		// The summary is withheld once the verdict changes, until one for the new one is approved.
+		assert_ok!(PublicaFides::appeal_claim(Origin::signed(2), accepted));
+		assert!(PublicaFides::public_summaries(0).is_empty());
+		let rejected = ClaimStatus::VeracityRejected;
+		assert_ok!(PublicaFides::decide_appeal(Origin::root(), accepted, rejected));
+		assert!(PublicaFides::public_summaries(0).is_empty());
+		assert_ok!(PublicaFides::approve_public_summary(committee_origin(2, 3), accepted, vec![8]));
+		let summary = ClaimSummary { id: accepted, status: rejected, summary_cid: vec![8] };
+		assert_eq!(PublicaFides::public_summaries(0), vec![summary]);
 	});
 }
@@ -0,0 +1,139 @@
+//! Read-only views of content and claims, as returned by the runtime API.
+
+use crate::{
+	AuthorId, ClaimId, ClaimStatus, Config, ContentStatus, ContentStorage, NextClaimId,
+	NextContentId, Pallet, PublisherId,
+};
+use codec::{Decode, Encode};
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::{
+	traits::{One, Saturating},
+	Permill, RuntimeDebug,
+};
+use sp_std::vec::Vec;
+
+/// Largest number of items looked at for one page of content or claims.
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Content together with its claims.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ContentInfo<ContentId> {
+	/// Id of the content.
+	pub id: ContentId,
+	/// The URL designated for accessing the content.
+	pub url: Vec<u8>,
+	/// The claims raised in the content, ordered as they were linked.
+	pub claims: Vec<ClaimInfo>,
+	/// Credibility score of the content, if it was scored.
+	pub score: Option<Permill>,
+	/// The author of the content, if known.
+	pub author: Option<AuthorId>,
+	/// The publisher of the content, if known.
+	pub publisher: Option<PublisherId>,
+	/// Where the content is in its review.
+	pub status: ContentStatus,
+	/// The DOI of the content, if it has one.
+	pub doi: Option<Vec<u8>>,
+	/// The IPFS CID of the snapshot of the content taken on submission, if any.
+	pub archive_cid: Option<Vec<u8>>,
+}
+
+/// A claim with its id.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ClaimInfo {
+	/// Id of the claim.
+	pub id: ClaimId,
+	/// The IPFS CID of the text that contains the claim statement.
+	pub claim_text_cid: Vec<u8>,
+	/// Where the claim currently is in the objectivity/veracity review.
+	pub status: ClaimStatus,
+}
+
+/// The approved plain-language summary of a claim.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct ClaimSummary {
+	/// Id of the claim.
+	pub id: ClaimId,
+	/// The final verdict on the claim, which the summary was approved for.
+	pub status: ClaimStatus,
+	/// The IPFS CID of the summary text.
+	pub summary_cid: Vec<u8>,
+}
+
+impl<T: Config> Pallet<T> {
+	/// The content with its claims, if it exists.
+	pub fn content_info(content_id: T::ContentId) -> Option<ContentInfo<T::ContentId>> {
+		let content = ContentStorage::<T>::try_get(content_id).ok()?;
+		Some(ContentInfo {
+			id: content_id,
+			url: content.url.into_inner(),
+			claims: content
+				.claims
+				.iter()
+				.filter_map(|claim_id| Self::claim_info(*claim_id))
+				.collect(),
+			score: content.score,
+			author: content.author,
+			publisher: content.publisher,
+			status: content.status,
+			doi: content.doi.map(|doi| doi.into_inner()),
+			archive_cid: content.archive_cid.map(|cid| cid.into_inner()),
+		})
+	}
+
+	/// The claim, if it exists.
+	pub fn claim_info(claim_id: ClaimId) -> Option<ClaimInfo> {
+		Self::get_claim(claim_id).map(|claim| ClaimInfo {
+			id: claim_id,
+			claim_text_cid: claim.claim_text_cid.into_inner(),
+			status: claim.status,
+		})
+	}
+
+	/// The claims with the given status among the `count` claim ids starting at `start`, ordered
+	/// by id. At most `MAX_PAGE_SIZE` ids are looked at, the next page starts at `start + count`.
+	pub fn claims_with_status(status: ClaimStatus, start: ClaimId, count: u32) -> Vec<ClaimInfo> {
+		let end = NextClaimId::<T>::get().min(start.saturating_add(count.min(MAX_PAGE_SIZE)));
+		(start..end)
+			.filter_map(Self::claim_info)
+			.filter(|claim| claim.status == status)
+			.collect()
+	}
+
+	/// The approved summaries of the claims raised in the content, ordered as the claims were
+	/// linked. Claims without a summary of their current verdict are left out.
+	pub fn public_summaries(content_id: T::ContentId) -> Vec<ClaimSummary> {
+		ContentStorage::<T>::get(content_id)
+			.claims
+			.iter()
+			.filter_map(|claim_id| {
+				let summary_cid = Self::approved_summary(*claim_id)?;
+				let status = Self::get_claim(claim_id)?.status;
+				Some(ClaimSummary { id: *claim_id, status, summary_cid })
+			})
+			.collect()
+	}
+
+	/// The score of the content, if it exists and was scored.
+	pub fn content_score(content_id: T::ContentId) -> Option<Permill> {
+		ContentStorage::<T>::try_get(content_id).ok()?.score
+	}
+
+	/// Up to `count` content items with an id of at least `start`, ordered by id. Ids of removed
+	/// content are skipped. `count` is capped at `MAX_PAGE_SIZE`.
+	pub fn content_page(start: T::ContentId, count: u32) -> Vec<ContentInfo<T::ContentId>> {
+		let end = NextContentId::<T>::get();
+		let count = count.min(MAX_PAGE_SIZE) as usize;
+		let mut page = Vec::new();
+		let mut content_id = start;
+		while content_id < end && page.len() < count {
+			page.extend(Self::content_info(content_id));
+			content_id = content_id.saturating_add(One::one());
+		}
+		page
+	}
+}
@@ -0,0 +1,432 @@
+//! Weights for publicafides
+//!
+//! These weights are not benchmarked yet. The execution times are conservative estimates, with a
+//! component for every byte and item a call handles, and the storage reads and writes are the
+//! ones a call makes in the worst case, counted from the code. Replace this file with the output
+//! of the benchmark CLI once the benchmarks in `benchmarking.rs` are run on reference hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for publicafides.
+pub trait WeightInfo {
+	fn store_content(u: u32, d: u32, a: u32, ) -> Weight;
+	fn store_correction(u: u32, d: u32, a: u32, ) -> Weight;
+	fn complete_review() -> Weight;
+	fn mark_as_spam(c: u32, ) -> Weight;
+	fn register_author(n: u32, a: u32, ) -> Weight;
+	fn register_publisher(n: u32, a: u32, ) -> Weight;
+	fn store_claim_for_content(s: u32, c: u32, ) -> Weight;
+	fn link_claim_to_content(c: u32, l: u32, ) -> Weight;
+	fn approve_claim_objectivity() -> Weight;
+	fn reject_claim_as_subjective(l: u32, v: u32, ) -> Weight;
+	fn accept_claim_veracity(l: u32, v: u32, ) -> Weight;
+	fn reject_claim_veracity(l: u32, v: u32, ) -> Weight;
+	fn record_votes(v: u32, ) -> Weight;
+	fn claim_reward() -> Weight;
+	fn appeal_claim(l: u32, ) -> Weight;
+	fn decide_appeal(l: u32, ) -> Weight;
+	fn set_claim_category() -> Weight;
+	fn set_review_period() -> Weight;
+	fn expire_verdicts(c: u32, ) -> Weight;
+	fn close_reviews(c: u32, ) -> Weight;
+	fn withdraw_content() -> Weight;
+	fn link_correction(c: u32, ) -> Weight;
+	fn approve_public_summary() -> Weight;
+}
+
+/// Weights for publicafides using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: crate::Config> WeightInfo for SubstrateWeight<T> {
+	fn store_content(u: u32, d: u32, a: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	fn store_correction(u: u32, d: u32, a: u32, ) -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn complete_review() -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(300_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(10 + claims))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Removing a claim removes the votes on it.
+	fn mark_as_spam(c: u32, ) -> Weight {
+		let voters = T::MaxVotersPerClaim::get() as Weight;
+		(52_000_000 as Weight)
+			.saturating_add((3_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().writes((10 + voters).saturating_mul(c as Weight)))
+	}
+	fn register_author(n: u32, a: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn register_publisher(n: u32, a: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	fn store_claim_for_content(s: u32, c: u32, ) -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((40_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn link_claim_to_content(c: u32, l: u32, ) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((4_200_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add((3_800_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	fn approve_claim_objectivity() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn reject_claim_as_subjective(l: u32, v: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn accept_claim_veracity(l: u32, v: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn reject_claim_veracity(l: u32, v: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	fn record_votes(v: u32, ) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((7_200_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
+	}
+	fn claim_reward() -> Weight {
+		(62_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Rescoring a content item reads each of its claims.
+	// Counting the verdicts in the review reads up to `MaxVerdicts` of them.
+	fn appeal_claim(l: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		let verdicts = T::MaxVerdicts::get() as Weight;
+		(52_000_000 as Weight)
+			.saturating_add((287_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 + verdicts))
+			.saturating_add(T::DbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn decide_appeal(l: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(58_000_000 as Weight)
+			.saturating_add((289_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(T::DbWeight::get().reads(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+	}
+	fn set_claim_category() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	fn set_review_period() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Every expired claim rescores all content it is linked to.
+	fn expire_verdicts(c: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		let links = T::MaxContentPerClaim::get() as Weight;
+		(8_000_000 as Weight)
+			.saturating_add((9_300_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 + links * (5 + claims)).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 + 3 * links).saturating_mul(c as Weight)))
+	}
+	// Rescoring a content item reads each of its claims.
+	fn close_reviews(c: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		(6_000_000 as Weight)
+			.saturating_add((298_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((10 + claims).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((8 as Weight).saturating_mul(c as Weight)))
+	}
+	fn withdraw_content() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+	// Linking a claim counts the content it is linked to.
+	fn link_correction(c: u32, ) -> Weight {
+		let claims = T::MaxClaimsPerContent::get() as Weight;
+		let links = T::MaxContentPerClaim::get() as Weight;
+		(260_000_000 as Weight)
+			.saturating_add((5_400_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(7 + claims))
+			.saturating_add(T::DbWeight::get().reads((3 + links).saturating_mul(c as Weight)))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn approve_public_summary() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// The `()` weights don't know the configuration, so they assume the bounds of the node runtime.
+const MAX_CLAIMS_PER_CONTENT: Weight = 64;
+const MAX_CONTENT_PER_CLAIM: Weight = 32;
+const MAX_VOTERS_PER_CLAIM: Weight = 100;
+const MAX_VERDICTS: Weight = 4;
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn store_content(u: u32, d: u32, a: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn store_correction(u: u32, d: u32, a: u32, ) -> Weight {
+		(66_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(u as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((4_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	fn complete_review() -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(300_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(10 + claims))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	fn mark_as_spam(c: u32, ) -> Weight {
+		let voters = MAX_VOTERS_PER_CLAIM;
+		(52_000_000 as Weight)
+			.saturating_add((3_100_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((6 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((10 + voters).saturating_mul(c as Weight)))
+	}
+	fn register_author(n: u32, a: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn register_publisher(n: u32, a: u32, ) -> Weight {
+		(22_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((60_000 as Weight).saturating_mul(a as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn store_claim_for_content(s: u32, c: u32, ) -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((40_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn link_claim_to_content(c: u32, l: u32, ) -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add((4_200_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add((3_800_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	fn approve_claim_objectivity() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reject_claim_as_subjective(l: u32, v: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	fn accept_claim_veracity(l: u32, v: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	fn reject_claim_veracity(l: u32, v: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(38_000_000 as Weight)
+			.saturating_add((290_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add((12_000_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(v as Weight)))
+	}
+	fn record_votes(v: u32, ) -> Weight {
+		(6_000_000 as Weight)
+			.saturating_add((7_200_000 as Weight).saturating_mul(v as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(v as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(v as Weight)))
+	}
+	fn claim_reward() -> Weight {
+		(62_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn appeal_claim(l: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		let verdicts = MAX_VERDICTS;
+		(52_000_000 as Weight)
+			.saturating_add((287_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 + verdicts))
+			.saturating_add(RocksDbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+	}
+	fn decide_appeal(l: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(58_000_000 as Weight)
+			.saturating_add((289_000_000 as Weight).saturating_mul(l as Weight))
+			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((5 + claims).saturating_mul(l as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(l as Weight)))
+	}
+	fn set_claim_category() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn set_review_period() -> Weight {
+		(14_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn expire_verdicts(c: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		let links = MAX_CONTENT_PER_CLAIM;
+		(8_000_000 as Weight)
+			.saturating_add((9_300_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 + links * (5 + claims)).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 + 3 * links).saturating_mul(c as Weight)))
+	}
+	fn close_reviews(c: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		(6_000_000 as Weight)
+			.saturating_add((298_000_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((10 + claims).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((8 as Weight).saturating_mul(c as Weight)))
+	}
+	fn withdraw_content() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+	fn link_correction(c: u32, ) -> Weight {
+		let claims = MAX_CLAIMS_PER_CONTENT;
+		let links = MAX_CONTENT_PER_CLAIM;
+		(260_000_000 as Weight)
+			.saturating_add((5_400_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(7 + claims))
+			.saturating_add(RocksDbWeight::get().reads((3 + links).saturating_mul(c as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(c as Weight)))
+	}
+	fn approve_public_summary() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
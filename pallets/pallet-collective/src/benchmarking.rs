@@ -20,24 +20,24 @@
 use super::*;
 
 use frame_benchmarking::{
-	account, benchmarks_instance, impl_benchmark_test_suite, whitelisted_caller,
+	account, benchmarks_instance_pallet, impl_benchmark_test_suite, whitelisted_caller,
 };
 use frame_system::RawOrigin as SystemOrigin;
 use sp_runtime::traits::Bounded;
 use sp_std::mem::size_of;
 
-use crate::Module as Collective;
+use crate::Pallet as Collective;
 use frame_system::{Call as SystemCall, Pallet as System};
 
 const SEED: u32 = 0;
 
 const MAX_BYTES: u32 = 1_024;
 
-fn assert_last_event<T: Config<I>, I: Instance>(generic_event: <T as Config<I>>::Event) {
+fn assert_last_event<T: Config<I>, I: 'static>(generic_event: <T as Config<I>>::Event) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
-benchmarks_instance! {
+benchmarks_instance_pallet! {
 	set_members {
 		let m in 1 .. T::MaxMembers::get();
 		let n in 1 .. T::MaxMembers::get();
@@ -129,7 +129,7 @@ benchmarks_instance! {
 		let proposal_hash = T::Hashing::hash_of(&proposal);
 		// Note that execution fails due to mis-matched origin
 		assert_last_event::<T, I>(
-			RawEvent::MemberExecuted(proposal_hash, Err(DispatchError::BadOrigin)).into()
+			Event::MemberExecuted(proposal_hash, Err(DispatchError::BadOrigin)).into()
 		);
 	}
 
@@ -154,14 +154,26 @@ benchmarks_instance! {
 
 		let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; b as usize]).into();
 		let threshold = 1;
-
-	}: propose(SystemOrigin::Signed(caller), threshold, Box::new(proposal.clone()), bytes_in_storage)
+		let direct = Collective::<T, _>::direct_execution_allowed();
+	}: {
+		// Collectives that only decide by vote reject the call right away.
+		if direct {
+			Collective::<T, _>::propose(
+				SystemOrigin::Signed(caller).into(),
+				threshold,
+				Box::new(proposal.clone()),
+				bytes_in_storage,
+			)?;
+		}
+	}
 	verify {
-		let proposal_hash = T::Hashing::hash_of(&proposal);
-		// Note that execution fails due to mis-matched origin
-		assert_last_event::<T, I>(
-			RawEvent::Executed(proposal_hash, Err(DispatchError::BadOrigin)).into()
-		);
+		if direct {
+			let proposal_hash = T::Hashing::hash_of(&proposal);
+			// Note that execution fails due to mis-matched origin
+			assert_last_event::<T, I>(
+				Event::Executed(proposal_hash, Err(DispatchError::BadOrigin)).into()
+			);
+		}
 	}
 
 	// This tests when proposal is created and queued as "proposed"
@@ -204,7 +216,7 @@ benchmarks_instance! {
 		// New proposal is recorded
 		assert_eq!(Collective::<T, _>::proposals().len(), p as usize);
 		let proposal_hash = T::Hashing::hash_of(&proposal);
-		assert_last_event::<T, I>(RawEvent::Proposed(caller, p - 1, proposal_hash, threshold).into());
+		assert_last_event::<T, I>(Event::Proposed(caller, p - 1, proposal_hash, threshold).into());
 	}
 
 	vote {
@@ -282,6 +294,222 @@ benchmarks_instance! {
 		assert_eq!(voting.nays.len(), 1);
 	}
 
+	commit_vote {
+		let m in 2 .. T::MaxMembers::get();
+
+		let b = MAX_BYTES;
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let voter: T::AccountId = account("voter", 0, SEED);
+		members.push(voter.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
+
+		let index = Collective::<T, _>::proposal_count();
+		let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; b as usize]).into();
+		Collective::<T, _>::propose(
+			SystemOrigin::Signed(voter.clone()).into(),
+			m,
+			Box::new(proposal.clone()),
+			bytes_in_storage,
+		)?;
+		let hash = T::Hashing::hash_of(&proposal);
+		let commitment = Collective::<T, _>::commitment_of(&voter, &hash, index, true, &[1; 32]);
+		let commit_reveal = T::VotingMode::get() != VotingMode::Open;
+	}: {
+		// Collectives with open voting reject the call right away.
+		if commit_reveal {
+			Collective::<T, _>::commit_vote(
+				SystemOrigin::Signed(voter.clone()).into(),
+				hash,
+				index,
+				commitment,
+			)?;
+		}
+	}
+	verify {
+		if commit_reveal {
+			assert_eq!(Collective::<T, _>::commitment(hash, &voter), Some(commitment));
+		}
+	}
+
+	reveal_vote {
+		let m in 2 .. T::MaxMembers::get();
+
+		let b = MAX_BYTES;
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let voter: T::AccountId = account("voter", 0, SEED);
+		members.push(voter.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
+
+		let index = Collective::<T, _>::proposal_count();
+		let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; b as usize]).into();
+		Collective::<T, _>::propose(
+			SystemOrigin::Signed(voter.clone()).into(),
+			m,
+			Box::new(proposal.clone()),
+			bytes_in_storage,
+		)?;
+		let hash = T::Hashing::hash_of(&proposal);
+		let salt = [1; 32];
+
+		let commit_reveal = match T::VotingMode::get() {
+			VotingMode::CommitReveal { reveal, .. } => {
+				for member in &members {
+					let commitment =
+						Collective::<T, _>::commitment_of(member, &hash, index, true, &salt);
+					Collective::<T, _>::commit_vote(
+						SystemOrigin::Signed(member.clone()).into(),
+						hash,
+						index,
+						commitment,
+					)?;
+				}
+				let end = Collective::<T, _>::voting(&hash).ok_or("motion missing")?.end;
+				System::<T>::set_block_number(end - reveal);
+				// Everyone else reveals first, so that the votes are as long as they get.
+				for member in &members[.. members.len() - 1] {
+					Collective::<T, _>::reveal_vote(
+						SystemOrigin::Signed(member.clone()).into(),
+						hash,
+						index,
+						true,
+						salt,
+					)?;
+				}
+				true
+			},
+			VotingMode::Open => false,
+		};
+	}: {
+		// Collectives with open voting reject the call right away.
+		if commit_reveal {
+			Collective::<T, _>::reveal_vote(
+				SystemOrigin::Signed(voter.clone()).into(),
+				hash,
+				index,
+				true,
+				salt,
+			)?;
+		}
+	}
+	verify {
+		if commit_reveal {
+			assert_last_event::<T, I>(Event::VoteRevealed(voter, hash, true).into());
+		}
+	}
+
+	abstain {
+		let m in 2 .. T::MaxMembers::get();
+
+		let b = MAX_BYTES;
+		let bytes_in_storage = b + size_of::<u32>() as u32;
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let voter: T::AccountId = account("voter", 0, SEED);
+		members.push(voter.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
+
+		let index = Collective::<T, _>::proposal_count();
+		let proposal: T::Proposal = SystemCall::<T>::remark(vec![1; b as usize]).into();
+		Collective::<T, _>::propose(
+			SystemOrigin::Signed(voter.clone()).into(),
+			m,
+			Box::new(proposal.clone()),
+			bytes_in_storage,
+		)?;
+		let hash = T::Hashing::hash_of(&proposal);
+
+		// Everyone else abstains first, so that the abstentions are as long as they get.
+		for member in &members[.. members.len() - 1] {
+			Collective::<T, _>::abstain(SystemOrigin::Signed(member.clone()).into(), hash, index)?;
+		}
+		// The voter withdraws a vote, or a commitment with secret voting.
+		if T::VotingMode::get() == VotingMode::Open {
+			Collective::<T, _>::vote(SystemOrigin::Signed(voter.clone()).into(), hash, index, true)?;
+		} else {
+			let commitment =
+				Collective::<T, _>::commitment_of(&voter, &hash, index, true, &[1; 32]);
+			Collective::<T, _>::commit_vote(
+				SystemOrigin::Signed(voter.clone()).into(),
+				hash,
+				index,
+				commitment,
+			)?;
+		}
+	}: _(SystemOrigin::Signed(voter.clone()), hash, index)
+	verify {
+		let voting = Collective::<T, _>::voting(&hash).ok_or("Proposal Missing")?;
+		assert_eq!(voting.abstentions.len(), m as usize);
+		assert!(voting.ayes.is_empty());
+		assert_last_event::<T, I>(Event::Abstained(voter, hash).into());
+	}
+
+	delegate {
+		let d in 0 .. T::MaxDelegationDepth::get().saturating_sub(1);
+		let m in (T::MaxDelegationDepth::get() + 1) .. T::MaxMembers::get();
+
+		// Construct `members`.
+		let mut members = vec![];
+		for i in 0 .. m - 1 {
+			let member = account("member", i, SEED);
+			members.push(member);
+		}
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		members.push(delegator.clone());
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members.clone(), None, T::MaxMembers::get())?;
+
+		// The delegate starts a chain of `d` delegations, which is checked for cycles.
+		for i in 0 .. d as usize {
+			Collective::<T, _>::delegate(
+				SystemOrigin::Signed(members[i].clone()).into(),
+				members[i + 1].clone(),
+			)?;
+		}
+		// The other members delegate to the end of the chain, so that every delegation is
+		// searched for delegators of the sender.
+		for member in &members[d as usize + 1 .. members.len() - 1] {
+			Collective::<T, _>::delegate(
+				SystemOrigin::Signed(member.clone()).into(),
+				members[d as usize].clone(),
+			)?;
+		}
+		let delegate = members[0].clone();
+	}: _(SystemOrigin::Signed(delegator.clone()), delegate.clone())
+	verify {
+		assert_eq!(Collective::<T, _>::delegation(&delegator), Some(delegate.clone()));
+		assert_last_event::<T, I>(Event::Delegated(delegator, delegate).into());
+	}
+
+	undelegate {
+		let delegator: T::AccountId = account("delegator", 0, SEED);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let members = vec![delegator.clone(), delegate.clone()];
+		Collective::<T, _>::set_members(SystemOrigin::Root.into(), members, None, T::MaxMembers::get())?;
+		Collective::<T, _>::delegate(SystemOrigin::Signed(delegator.clone()).into(), delegate)?;
+	}: _(SystemOrigin::Signed(delegator.clone()))
+	verify {
+		assert_eq!(Collective::<T, _>::delegation(&delegator), None);
+		assert_last_event::<T, I>(Event::Undelegated(delegator).into());
+	}
+
 	close_early_disapproved {
 		// We choose 4 as a minimum so we always trigger a vote in the voting loop (`for j in ...`)
 		let m in 4 .. T::MaxMembers::get();
@@ -358,7 +586,7 @@ benchmarks_instance! {
 	verify {
 		// The last proposal is removed.
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(RawEvent::Disapproved(last_hash).into());
+		assert_last_event::<T, I>(Event::Disapproved(last_hash).into());
 	}
 
 	close_early_approved {
@@ -439,7 +667,7 @@ benchmarks_instance! {
 	verify {
 		// The last proposal is removed.
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(RawEvent::Executed(last_hash, Err(DispatchError::BadOrigin)).into());
+		assert_last_event::<T, I>(Event::Executed(last_hash, Err(DispatchError::BadOrigin)).into());
 	}
 
 	close_disapproved {
@@ -511,7 +739,7 @@ benchmarks_instance! {
 	}: close(SystemOrigin::Signed(caller), last_hash, index, Weight::max_value(), bytes_in_storage)
 	verify {
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(RawEvent::Disapproved(last_hash).into());
+		assert_last_event::<T, I>(Event::Disapproved(last_hash).into());
 	}
 
 	close_approved {
@@ -583,7 +811,7 @@ benchmarks_instance! {
 	}: close(SystemOrigin::Signed(caller), last_hash, p - 1, Weight::max_value(), bytes_in_storage)
 	verify {
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(RawEvent::Executed(last_hash, Err(DispatchError::BadOrigin)).into());
+		assert_last_event::<T, I>(Event::Executed(last_hash, Err(DispatchError::BadOrigin)).into());
 	}
 
 	disapprove_proposal {
@@ -631,7 +859,7 @@ benchmarks_instance! {
 	}: _(SystemOrigin::Root, last_hash)
 	verify {
 		assert_eq!(Collective::<T, _>::proposals().len(), (p - 1) as usize);
-		assert_last_event::<T, I>(RawEvent::Disapproved(last_hash).into());
+		assert_last_event::<T, I>(Event::Disapproved(last_hash).into());
 	}
 }
 
@@ -20,6 +20,8 @@
 //!
 //! The membership can be provided in one of two ways: either directly, using the Root-dispatchable
 //! function `set_members`, or indirectly, through implementing the `ChangeMembers`.
+//! `set_members` only accepts accounts in `MembershipFilter`, e.g. the approved reviewers of a
+//! field.
 //! The pallet assumes that the amount of members stays at or below `MaxMembers` for its weight
 //! calculations, but enforces this neither in `set_members` nor in `change_members_sorted`.
 //!
@@ -38,32 +40,60 @@
 //! abstentions and the proposal is executed if there are enough approvals counting the new votes.
 //!
 //! If there are not, or if no prime is set, then the motion is dropped without being executed.
+//!
+//! Once a motion is closed, the members who voted aye and nay are reported to `OnProposalClosed`,
+//! e.g. to track how the votes of members compare to later outcomes.
+//!
+//! Members can also `abstain` explicitly. Abstentions count towards the `Quorum`, the share of
+//! members who must take part in a motion, but neither for nor against it. Once the voting period
+//! is over, a motion without quorum is dropped regardless of its votes, and the default vote only
+//! applies to the members who didn't take part at all.
+//!
+//! Votes can be weighted with `VoteWeight`, e.g. by the reputation or locked stake of members.
+//! Thresholds, the quorum and the tallies of closed motions are then in vote weight rather than in
+//! members, and approved motions are dispatched with `RawOrigin::WeightedMembers`, which the
+//! proportion origins like `EnsureProportionAtLeast` accept just like `RawOrigin::Members`.
+//!
+//! Members can `delegate` their vote to another member, e.g. while they are on leave. When a
+//! motion is closed, a member who didn't take part follows the vote or abstention of their
+//! delegate, or of the delegate's delegate, up to `MaxDelegationDepth` delegations away. Cycles of
+//! delegations are rejected, and delegations from and to members who leave the collective are
+//! dropped.
+//!
+//! With `VotingMode::CommitReveal`, votes stay secret until everyone has voted. Members first
+//! commit to the hash of their vote and a salt with `commit_vote`, and reveal the vote with
+//! `reveal_vote` once the commit window is over. Only revealed votes are counted when the motion
+//! is closed. Such collectives don't execute proposals directly (`threshold < 2`) either, and
+//! neither do collectives with a `Quorum` or with weighted votes.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "128"]
 
 use sp_core::u32_trait::Value as U32;
 use sp_io::storage;
-use sp_runtime::{traits::Hash, RuntimeDebug};
-use sp_std::{prelude::*, result};
+use sp_runtime::{
+	traits::{Hash, Zero},
+	Perbill, RuntimeDebug,
+};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, prelude::*, result};
 
 use frame_support::{
 	codec::{Decode, Encode},
-	decl_error, decl_event, decl_module, decl_storage,
-	dispatch::{
-		DispatchError, DispatchResult, DispatchResultWithPostInfo, Dispatchable, Parameter,
-		PostDispatchInfo,
-	},
+	dispatch::{DispatchError, DispatchResultWithPostInfo, Dispatchable, PostDispatchInfo},
 	ensure,
-	traits::{Backing, ChangeMembers, EnsureOrigin, Get, GetBacking, InitializeMembers},
-	weights::{DispatchClass, GetDispatchInfo, Pays, Weight},
-	BoundedVec,
+	traits::{
+		Backing, ChangeMembers, Contains, EnsureOrigin, Get, GetBacking, InitializeMembers,
+		SortedMembers,
+	},
+	weights::{GetDispatchInfo, Weight},
 };
-use frame_system::{self as system, ensure_root, ensure_signed};
+
+pub use pallet::*;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod migrations;
 pub mod weights;
 pub use weights::WeightInfo;
 
@@ -122,37 +152,88 @@ impl DefaultVote for MoreThanMajorityThenPrimeDefaultVote {
 	}
 }
 
-pub trait Config<I: Instance = DefaultInstance>: frame_system::Config {
-	/// The outer origin type.
-	type Origin: From<RawOrigin<Self::AccountId, I>>;
+/// Handler for the votes on motions of a collective once they are closed.
+pub trait OnProposalClosed<AccountId, Proposal, Origin> {
+	/// Called when a motion is closed or disapproved, before an approved `proposal` is executed.
+	/// `origin` is the origin the proposal is executed with if approved, which tells handlers
+	/// serving several collectives which collective the motion belongs to. `ayes` and `nays` are
+	/// the members who voted, abstentions are not included.
+	///
+	/// Returns the weight consumed.
+	fn on_proposal_closed(
+		origin: Origin,
+		proposal: &Proposal,
+		approved: bool,
+		ayes: &[AccountId],
+		nays: &[AccountId],
+	) -> Weight;
+
+	/// The maximum weight consumed by `on_proposal_closed` in a collective of `members` members.
+	fn max_weight(members: MemberCount) -> Weight;
+}
+
+/// Weight of the votes of members, e.g. by their reputation or locked stake.
+///
+/// Sums of vote weights are kept as `MemberCount`, so the total weight of a collective must fit.
+pub trait VoteWeight<AccountId> {
+	/// Whether votes are weighted. Motions of collectives with weighted votes are approved with
+	/// `RawOrigin::WeightedMembers` rather than `RawOrigin::Members`.
+	const WEIGHTED: bool = true;
 
-	/// The outer call dispatch type.
-	type Proposal: Parameter
-		+ Dispatchable<Origin = <Self as Config<I>>::Origin, PostInfo = PostDispatchInfo>
-		+ From<frame_system::Call<Self>>
-		+ GetDispatchInfo;
+	/// The weight of the vote of `who`.
+	fn vote_weight(who: &AccountId) -> MemberCount;
 
-	/// The outer event type.
-	type Event: From<Event<Self, I>> + Into<<Self as frame_system::Config>::Event>;
+	/// The maximum weight consumed by looking up the vote weights of `members` members.
+	fn max_weight(members: MemberCount) -> Weight;
+}
 
-	/// The time-out for council motions.
-	type MotionDuration: Get<Self::BlockNumber>;
+/// Every member has a single vote.
+impl<AccountId> VoteWeight<AccountId> for () {
+	const WEIGHTED: bool = false;
 
-	/// Maximum number of proposals allowed to be active in parallel.
-	type MaxProposals: Get<ProposalIndex>;
+	fn vote_weight(_: &AccountId) -> MemberCount {
+		1
+	}
 
-	/// The maximum number of members supported by the pallet. Used for weight estimation.
-	///
-	/// NOTE:
-	/// + Benchmarks will need to be re-run and weights adjusted if this changes.
-	/// + This pallet assumes that dependents keep to the limit without enforcing it.
-	type MaxMembers: Get<MemberCount>;
+	fn max_weight(_: MemberCount) -> Weight {
+		0
+	}
+}
+
+/// How the members of a collective vote on motions.
+#[derive(PartialEq, Eq, Clone, Copy, RuntimeDebug)]
+pub enum VotingMode<BlockNumber> {
+	/// Members vote with `vote`, and every vote is public right away.
+	Open,
+	/// Members commit to their vote with `commit_vote` during the first `commit` blocks of a
+	/// motion, and reveal it with `reveal_vote` during the following `reveal` blocks. Motions end
+	/// after both windows, regardless of `MotionDuration`.
+	CommitReveal { commit: BlockNumber, reveal: BlockNumber },
+}
+
+/// Open voting, for collectives which don't keep votes secret.
+pub struct OpenVoting;
+
+impl<BlockNumber> Get<VotingMode<BlockNumber>> for OpenVoting {
+	fn get() -> VotingMode<BlockNumber> {
+		VotingMode::Open
+	}
+}
 
-	/// Default vote strategy of this collective.
-	type DefaultVote: DefaultVote;
+impl<AccountId, Proposal, Origin> OnProposalClosed<AccountId, Proposal, Origin> for () {
+	fn on_proposal_closed(
+		_: Origin,
+		_: &Proposal,
+		_: bool,
+		_: &[AccountId],
+		_: &[AccountId],
+	) -> Weight {
+		0
+	}
 
-	/// Weight information for extrinsics in this pallet.
-	type WeightInfo: WeightInfo;
+	fn max_weight(_: MemberCount) -> Weight {
+		0
+	}
 }
 
 /// Origin for the collective module.
@@ -162,6 +243,9 @@ pub enum RawOrigin<AccountId, I> {
 	Members(MemberCount, MemberCount),
 	/// It has been condoned by a single member of the collective.
 	Member(AccountId),
+	/// It has been condoned by members of the collective with a given vote weight from a given
+	/// total, in a collective with weighted votes.
+	WeightedMembers(MemberCount, MemberCount),
 	/// Dummy to manage the fact we have instancing.
 	_Phantom(sp_std::marker::PhantomData<I>),
 }
@@ -169,93 +253,247 @@ pub enum RawOrigin<AccountId, I> {
 impl<AccountId, I> GetBacking for RawOrigin<AccountId, I> {
 	fn get_backing(&self) -> Option<Backing> {
 		match self {
-			RawOrigin::Members(n, d) => Some(Backing { approvals: *n, eligible: *d }),
+			RawOrigin::Members(n, d) | RawOrigin::WeightedMembers(n, d) =>
+				Some(Backing { approvals: *n, eligible: *d }),
 			_ => None,
 		}
 	}
 }
 
-/// Origin for the collective module.
-pub type Origin<T, I = DefaultInstance> = RawOrigin<<T as frame_system::Config>::AccountId, I>;
-
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug)]
 /// Info for keeping track of a motion being voted on.
 pub struct Votes<AccountId, BlockNumber> {
 	/// The proposal's unique index.
 	index: ProposalIndex,
-	/// The number of approval votes that are needed to pass the motion.
+	/// The number of approval votes, or their weight with weighted votes, that are needed to
+	/// pass the motion.
 	threshold: MemberCount,
 	/// The current set of voters that approved it.
 	ayes: Vec<AccountId>,
 	/// The current set of voters that rejected it.
 	nays: Vec<AccountId>,
+	/// The current set of voters that abstained.
+	abstentions: Vec<AccountId>,
 	/// The hard end time of this vote.
 	end: BlockNumber,
 }
 
-decl_storage! {
-	trait Store for Module<T: Config<I>, I: Instance=DefaultInstance> as Collective {
-		/// The hashes of the active proposals.
-		pub Proposals get(fn proposals): BoundedVec<T::Hash, T::MaxProposals>;
-		/// Actual proposal for a given hash, if it's current.
-		pub ProposalOf get(fn proposal_of):
-			map hasher(identity) T::Hash => Option<<T as Config<I>>::Proposal>;
-		/// Votes on a given proposal, if it is ongoing.
-		pub Voting get(fn voting):
-			map hasher(identity) T::Hash => Option<Votes<T::AccountId, T::BlockNumber>>;
-		/// Proposals so far.
-		pub ProposalCount get(fn proposal_count): u32;
-		/// The current members of the collective. This is stored sorted (just by value).
-		pub Members get(fn members): Vec<T::AccountId>;
-		/// The prime member that helps determine the default vote behavior in case of absentations.
-		pub Prime get(fn prime): Option<T::AccountId>;
-	}
-	add_extra_genesis {
-		config(phantom): sp_std::marker::PhantomData<I>;
-		config(members): Vec<T::AccountId>;
-		build(|config| {
+/// Return the weight of a dispatch call result as an `Option`.
+///
+/// Will return the weight regardless of what the state of the result is.
+fn get_result_weight(result: DispatchResultWithPostInfo) -> Option<Weight> {
+	match result {
+		Ok(post_info) => post_info.actual_weight,
+		Err(err) => err.post_info.actual_weight,
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	/// The current storage version.
+	const STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::storage_version(STORAGE_VERSION)]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		/// The outer origin type.
+		type Origin: From<RawOrigin<Self::AccountId, I>>;
+
+		/// The outer call dispatch type.
+		type Proposal: Parameter
+			+ Dispatchable<Origin = <Self as Config<I>>::Origin, PostInfo = PostDispatchInfo>
+			+ From<frame_system::Call<Self>>
+			+ GetDispatchInfo;
+
+		/// The outer event type.
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The time-out for council motions.
+		type MotionDuration: Get<Self::BlockNumber>;
+
+		/// Maximum number of proposals allowed to be active in parallel.
+		type MaxProposals: Get<ProposalIndex>;
+
+		/// The maximum number of members supported by the pallet. Used for weight estimation.
+		///
+		/// NOTE:
+		/// + Benchmarks will need to be re-run and weights adjusted if this changes.
+		/// + This pallet assumes that dependents keep to the limit without enforcing it.
+		type MaxMembers: Get<MemberCount>;
+
+		/// Default vote strategy of this collective.
+		type DefaultVote: DefaultVote;
+
+		/// Accounts that `set_members` may make members of this collective.
+		type MembershipFilter: Contains<Self::AccountId>;
+
+		/// Handler for the votes on motions once they are closed.
+		type OnProposalClosed: OnProposalClosed<
+			Self::AccountId,
+			<Self as Config<I>>::Proposal,
+			<Self as Config<I>>::Origin,
+		>;
+
+		/// Whether votes are public right away or committed first and revealed later.
+		type VotingMode: Get<VotingMode<Self::BlockNumber>>;
+
+		/// The minimum share of members who must vote or abstain for a motion to pass.
+		type Quorum: Get<Perbill>;
+
+		/// The weight of the votes of members. `()` gives every member a single vote.
+		type VoteWeight: VoteWeight<Self::AccountId>;
+
+		/// The maximum number of delegations between a member and the delegate whose vote they
+		/// follow. `()` disables delegation.
+		type MaxDelegationDepth: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		pub phantom: PhantomData<I>,
+		pub members: Vec<T::AccountId>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { phantom: Default::default(), members: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
 			use sp_std::collections::btree_set::BTreeSet;
-			let members_set: BTreeSet<_> = config.members.iter().collect();
-			assert!(members_set.len() == config.members.len(), "Members cannot contain duplicate accounts.");
+			let members_set: BTreeSet<_> = self.members.iter().collect();
+			assert_eq!(
+				members_set.len(),
+				self.members.len(),
+				"Members cannot contain duplicate accounts."
+			);
 
-			Module::<T, I>::initialize_members(&config.members)
-		});
+			Pallet::<T, I>::initialize_members(&self.members)
+		}
 	}
-}
 
-decl_event! {
-	pub enum Event<T, I=DefaultInstance> where
-		<T as frame_system::Config>::Hash,
-		<T as frame_system::Config>::AccountId,
-	{
+	/// Origin for the collective pallet.
+	#[pallet::origin]
+	pub type Origin<T, I = ()> = RawOrigin<<T as frame_system::Config>::AccountId, I>;
+
+	/// The hashes of the active proposals.
+	#[pallet::storage]
+	#[pallet::getter(fn proposals)]
+	pub type Proposals<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::Hash, T::MaxProposals>, ValueQuery>;
+
+	/// Actual proposal for a given hash, if it's current.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_of)]
+	pub type ProposalOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, <T as Config<I>>::Proposal, OptionQuery>;
+
+	/// Votes on a given proposal, if it is ongoing.
+	#[pallet::storage]
+	#[pallet::getter(fn voting)]
+	pub type Voting<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Identity, T::Hash, Votes<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Proposals so far.
+	#[pallet::storage]
+	#[pallet::getter(fn proposal_count)]
+	pub type ProposalCount<T: Config<I>, I: 'static = ()> = StorageValue<_, u32, ValueQuery>;
+
+	/// The current members of the collective. This is stored sorted (just by value).
+	#[pallet::storage]
+	#[pallet::getter(fn members)]
+	pub type Members<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, Vec<T::AccountId>, ValueQuery>;
+
+	/// The prime member that helps determine the default vote behavior in case of absentations.
+	#[pallet::storage]
+	#[pallet::getter(fn prime)]
+	pub type Prime<T: Config<I>, I: 'static = ()> = StorageValue<_, T::AccountId, OptionQuery>;
+
+	/// Commitments of members to their vote on a given proposal, which weren't revealed yet.
+	/// Only used with `VotingMode::CommitReveal`.
+	#[pallet::storage]
+	#[pallet::getter(fn commitment)]
+	pub type Commitments<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Identity,
+		T::Hash,
+		Twox64Concat,
+		T::AccountId,
+		T::Hash,
+		OptionQuery,
+	>;
+
+	/// The member whose vote a given member follows in motions they don't take part in.
+	#[pallet::storage]
+	#[pallet::getter(fn delegation)]
+	pub type Delegations<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, T::AccountId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	#[pallet::metadata(T::AccountId = "AccountId", T::Hash = "Hash")]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
 		/// A motion (given hash) has been proposed (by given account) with a threshold (given
 		/// `MemberCount`).
 		/// \[account, proposal_index, proposal_hash, threshold\]
-		Proposed(AccountId, ProposalIndex, Hash, MemberCount),
+		Proposed(T::AccountId, ProposalIndex, T::Hash, MemberCount),
 		/// A motion (given hash) has been voted on by given account, leaving
 		/// a tally (yes votes and no votes given respectively as `MemberCount`).
 		/// \[account, proposal_hash, voted, yes, no\]
-		Voted(AccountId, Hash, bool, MemberCount, MemberCount),
+		Voted(T::AccountId, T::Hash, bool, MemberCount, MemberCount),
 		/// A motion was approved by the required threshold.
 		/// \[proposal_hash\]
-		Approved(Hash),
+		Approved(T::Hash),
 		/// A motion was not approved by the required threshold.
 		/// \[proposal_hash\]
-		Disapproved(Hash),
+		Disapproved(T::Hash),
 		/// A motion was executed; result will be `Ok` if it returned without error.
 		/// \[proposal_hash, result\]
-		Executed(Hash, DispatchResult),
+		Executed(T::Hash, DispatchResult),
 		/// A single member did some action; result will be `Ok` if it returned without error.
 		/// \[proposal_hash, result\]
-		MemberExecuted(Hash, DispatchResult),
+		MemberExecuted(T::Hash, DispatchResult),
 		/// A proposal was closed because its threshold was reached or after its duration was up.
 		/// \[proposal_hash, yes, no\]
-		Closed(Hash, MemberCount, MemberCount),
+		Closed(T::Hash, MemberCount, MemberCount),
+		/// A member committed to a secret vote on a motion.
+		/// \[account, proposal_hash\]
+		VoteCommitted(T::AccountId, T::Hash),
+		/// A member revealed their vote on a motion.
+		/// \[account, proposal_hash, voted\]
+		VoteRevealed(T::AccountId, T::Hash, bool),
+		/// A member abstained from a motion.
+		/// \[account, proposal_hash\]
+		Abstained(T::AccountId, T::Hash),
+		/// A motion was not approved because too few members voted or abstained.
+		/// \[proposal_hash, participants, quorum\]
+		DisapprovedWithoutQuorum(T::Hash, MemberCount, MemberCount),
+		/// A member delegated their vote to another member.
+		/// \[delegator, delegate\]
+		Delegated(T::AccountId, T::AccountId),
+		/// The delegation of a member was withdrawn, or dropped as one of them left.
+		/// \[delegator\]
+		Undelegated(T::AccountId),
 	}
-}
 
-decl_error! {
-	pub enum Error for Module<T: Config<I>, I: Instance> {
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
 		/// Account is not a member
 		NotMember,
 		/// Duplicate proposals not allowed
@@ -276,26 +514,36 @@ decl_error! {
 		WrongProposalWeight,
 		/// The given length bound for the proposal was too low.
 		WrongProposalLength,
+		/// An account is not allowed to become a member.
+		MemberNotAllowed,
+		/// The call doesn't match the `VotingMode` of the collective.
+		WrongVotingMode,
+		/// The commit window of the motion is over.
+		CommitWindowOver,
+		/// Votes can only be revealed after the commit window and before the end of the motion.
+		NotRevealWindow,
+		/// The member didn't commit to a vote on the motion, or already revealed it.
+		NoCommitment,
+		/// The revealed vote and salt don't match the commitment.
+		CommitmentMismatch,
+		/// The delegate is not a member.
+		DelegateNotMember,
+		/// The delegation would close a cycle of delegations.
+		DelegationCycle,
+		/// The delegation would make a chain of more than `MaxDelegationDepth` delegations.
+		DelegationTooDeep,
+		/// The member doesn't delegate their vote.
+		NotDelegating,
+		/// The collective doesn't execute proposals directly, they must be put up for a vote.
+		MotionRequired,
 	}
-}
-
-/// Return the weight of a dispatch call result as an `Option`.
-///
-/// Will return the weight regardless of what the state of the result is.
-fn get_result_weight(result: DispatchResultWithPostInfo) -> Option<Weight> {
-	match result {
-		Ok(post_info) => post_info.actual_weight,
-		Err(err) => err.post_info.actual_weight,
-	}
-}
 
-// Note that councillor operations are assigned to the operational class.
-decl_module! {
-	pub struct Module<T: Config<I>, I: Instance=DefaultInstance> for enum Call where origin: <T as frame_system::Config>::Origin {
-		type Error = Error<T, I>;
-
-		fn deposit_event() = default;
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {}
 
+	// Note that councillor operations are assigned to the operational class.
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		/// Set the collective's membership.
 		///
 		/// - `new_members`: The new member list. Be nice to the chain and provide it sorted.
@@ -303,7 +551,7 @@ decl_module! {
 		/// - `old_count`: The upper bound for the previous number of members in storage.
 		///                Used for weight estimation.
 		///
-		/// Requires root origin.
+		/// Requires root origin. Every new member must be in `MembershipFilter`.
 		///
 		/// NOTE: Does not enforce the expected `MaxMembers` limit on the amount of members, but
 		///       the weight estimations rely on it to estimate dispatchable weight.
@@ -320,20 +568,25 @@ decl_module! {
 		///   - `P` storage mutations (codec `O(M)`) for updating the votes for each proposal
 		///   - 1 storage write (codec `O(1)`) for deleting the old `prime` and setting the new one
 		/// # </weight>
-		#[weight = (
+		#[pallet::weight((
 			T::WeightInfo::set_members(
 				*old_count, // M
 				new_members.len() as u32, // N
 				T::MaxProposals::get() // P
 			),
 			DispatchClass::Operational
-		)]
-		fn set_members(origin,
+		))]
+		pub fn set_members(
+			origin: OriginFor<T>,
 			new_members: Vec<T::AccountId>,
 			prime: Option<T::AccountId>,
 			old_count: MemberCount,
 		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
+			ensure!(
+				new_members.iter().all(|member| T::MembershipFilter::contains(member)),
+				Error::<T, I>::MemberNotAllowed
+			);
 			if new_members.len() > T::MaxMembers::get() as usize {
 				log::error!(
 					target: "runtime::collective",
@@ -374,16 +627,17 @@ decl_module! {
 		/// - DB: 1 read (codec `O(M)`) + DB access of `proposal`
 		/// - 1 event
 		/// # </weight>
-		#[weight = (
+		#[pallet::weight((
 			T::WeightInfo::execute(
 				*length_bound, // B
 				T::MaxMembers::get(), // M
 			).saturating_add(proposal.get_dispatch_info().weight), // P
 			DispatchClass::Operational
-		)]
-		fn execute(origin,
+		))]
+		pub fn execute(
+			origin: OriginFor<T>,
 			proposal: Box<<T as Config<I>>::Proposal>,
-			#[compact] length_bound: u32,
+			#[pallet::compact] length_bound: u32,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let members = Self::members();
@@ -394,7 +648,7 @@ decl_module! {
 			let proposal_hash = T::Hashing::hash_of(&proposal);
 			let result = proposal.dispatch(RawOrigin::Member(who).into());
 			Self::deposit_event(
-				RawEvent::MemberExecuted(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
+				Event::MemberExecuted(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
 			);
 
 			Ok(get_result_weight(result).map(|w| {
@@ -410,7 +664,12 @@ decl_module! {
 		/// Requires the sender to be member.
 		///
 		/// `threshold` determines whether `proposal` is executed directly (`threshold < 2`)
-		/// or put up for voting.
+		/// or put up for voting. With weighted votes, it is the vote weight needed to approve
+		/// the proposal.
+		///
+		/// Direct execution is only available with `VotingMode::Open`, without a `Quorum` and
+		/// without weighted votes, so that a single member can't sidestep a secret vote, the share
+		/// of members who must take part in a motion or the vote weight of the other members.
 		///
 		/// # <weight>
 		/// ## Weight
@@ -432,8 +691,8 @@ decl_module! {
 		///       - 1 storage write `Voting` (codec `O(M)`)
 		///   - 1 event
 		/// # </weight>
-		#[weight = (
-			if *threshold < 2 {
+		#[pallet::weight((
+			if *threshold < 2 && Pallet::<T, I>::direct_execution_allowed() {
 				T::WeightInfo::propose_execute(
 					*length_bound, // B
 					T::MaxMembers::get(), // M
@@ -446,11 +705,12 @@ decl_module! {
 				)
 			},
 			DispatchClass::Operational
-		)]
-		fn propose(origin,
-			#[compact] threshold: MemberCount,
+		))]
+		pub fn propose(
+			origin: OriginFor<T>,
+			#[pallet::compact] threshold: MemberCount,
 			proposal: Box<<T as Config<I>>::Proposal>,
-			#[compact] length_bound: u32
+			#[pallet::compact] length_bound: u32,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
 			let members = Self::members();
@@ -460,12 +720,16 @@ decl_module! {
 			ensure!(proposal_len <= length_bound as usize, Error::<T, I>::WrongProposalLength);
 			let proposal_hash = T::Hashing::hash_of(&proposal);
 			ensure!(!<ProposalOf<T, I>>::contains_key(proposal_hash), Error::<T, I>::DuplicateProposal);
+			ensure!(
+				threshold >= 2 || Self::direct_execution_allowed(),
+				Error::<T, I>::MotionRequired
+			);
 
 			if threshold < 2 {
-				let seats = Self::members().len() as MemberCount;
+				let seats = members.len() as MemberCount;
 				let result = proposal.dispatch(RawOrigin::Members(1, seats).into());
 				Self::deposit_event(
-					RawEvent::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
+					Event::Executed(proposal_hash, result.map(|_| ()).map_err(|e| e.error))
 				);
 
 				Ok(get_result_weight(result).map(|w| {
@@ -481,15 +745,19 @@ decl_module! {
 						Ok(proposals.len())
 					})?;
 				let index = Self::proposal_count();
-				<ProposalCount<I>>::mutate(|i| *i += 1);
+				ProposalCount::<T, I>::mutate(|i| *i += 1);
 				<ProposalOf<T, I>>::insert(proposal_hash, *proposal);
 				let votes = {
-					let end = system::Pallet::<T>::block_number() + T::MotionDuration::get();
-					Votes { index, threshold, ayes: vec![], nays: vec![], end }
+					let duration = match T::VotingMode::get() {
+						VotingMode::Open => T::MotionDuration::get(),
+						VotingMode::CommitReveal { commit, reveal } => commit + reveal,
+					};
+					let end = frame_system::Pallet::<T>::block_number() + duration;
+					Votes { index, threshold, ayes: vec![], nays: vec![], abstentions: vec![], end }
 				};
 				<Voting<T, I>>::insert(proposal_hash, votes);
 
-				Self::deposit_event(RawEvent::Proposed(who, index, proposal_hash, threshold));
+				Self::deposit_event(Event::Proposed(who, index, proposal_hash, threshold));
 
 				Ok(Some(T::WeightInfo::propose_proposed(
 					proposal_len as u32, // B
@@ -505,6 +773,8 @@ decl_module! {
 		///
 		/// Transaction fees will be waived if the member is voting on any particular proposal
 		/// for the first time and the call is successful. Subsequent vote changes will charge a fee.
+		///
+		/// Only available with `VotingMode::Open`.
 		/// # <weight>
 		/// ## Weight
 		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
@@ -513,16 +783,18 @@ decl_module! {
 		///   - 1 storage mutation `Voting` (codec `O(M)`)
 		/// - 1 event
 		/// # </weight>
-		#[weight = (
+		#[pallet::weight((
 			T::WeightInfo::vote(T::MaxMembers::get()),
 			DispatchClass::Operational
-		)]
-		fn vote(origin,
+		))]
+		pub fn vote(
+			origin: OriginFor<T>,
 			proposal: T::Hash,
-			#[compact] index: ProposalIndex,
+			#[pallet::compact] index: ProposalIndex,
 			approve: bool,
 		) -> DispatchResultWithPostInfo {
 			let who = ensure_signed(origin)?;
+			ensure!(T::VotingMode::get() == VotingMode::Open, Error::<T, I>::WrongVotingMode);
 			let members = Self::members();
 			ensure!(members.contains(&who), Error::<T, I>::NotMember);
 
@@ -531,9 +803,14 @@ decl_module! {
 
 			let position_yes = voting.ayes.iter().position(|a| a == &who);
 			let position_no = voting.nays.iter().position(|a| a == &who);
+			let position_abstain = voting.abstentions.iter().position(|a| a == &who);
 
 			// Detects first vote of the member in the motion
-			let is_account_voting_first_time = position_yes.is_none() && position_no.is_none();
+			let is_account_voting_first_time =
+				position_yes.is_none() && position_no.is_none() && position_abstain.is_none();
+			if let Some(pos) = position_abstain {
+				voting.abstentions.swap_remove(pos);
+			}
 
 			if approve {
 				if position_yes.is_none() {
@@ -557,7 +834,7 @@ decl_module! {
 
 			let yes_votes = voting.ayes.len() as MemberCount;
 			let no_votes = voting.nays.len() as MemberCount;
-			Self::deposit_event(RawEvent::Voted(who, proposal, approve, yes_votes, no_votes));
+			Self::deposit_event(Event::Voted(who, proposal, approve, yes_votes, no_votes));
 
 			Voting::<T, I>::insert(&proposal, voting);
 
@@ -574,6 +851,253 @@ decl_module! {
 			}
 		}
 
+		/// Commit to a secret vote on the given proposal, as the hash of the member, the motion,
+		/// the vote and a salt, see `commitment_of`. The commitment can be replaced until the
+		/// commit window is over.
+		///
+		/// Requires the sender to be a member. Only available with `VotingMode::CommitReveal`.
+		///
+		/// Transaction fees will be waived for the first commitment of the member to a vote on
+		/// the proposal.
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage read `Voting` (codec `O(M)`)
+		///   - 1 storage mutation `Commitments` (codec `O(1)`)
+		/// - 1 event
+		/// # </weight>
+		#[pallet::weight((
+			T::WeightInfo::commit_vote(T::MaxMembers::get()),
+			DispatchClass::Operational
+		))]
+		pub fn commit_vote(
+			origin: OriginFor<T>,
+			proposal: T::Hash,
+			#[pallet::compact] index: ProposalIndex,
+			commitment: T::Hash,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let reveal = match T::VotingMode::get() {
+				VotingMode::CommitReveal { reveal, .. } => reveal,
+				VotingMode::Open => Err(Error::<T, I>::WrongVotingMode)?,
+			};
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+			let voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			ensure!(!voting.abstentions.contains(&who), Error::<T, I>::DuplicateVote);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now + reveal < voting.end, Error::<T, I>::CommitWindowOver);
+
+			let is_first_commitment = Commitments::<T, I>::mutate(&proposal, &who, |c| {
+				c.replace(commitment).is_none()
+			});
+			Self::deposit_event(Event::VoteCommitted(who, proposal));
+
+			let pays_fee = if is_first_commitment { Pays::No } else { Pays::Yes };
+			Ok((Some(T::WeightInfo::commit_vote(members.len() as u32)), pays_fee).into())
+		}
+
+		/// Reveal the vote the sender committed to with `commit_vote`, which counts it.
+		///
+		/// Requires the sender to be a member. Only available with `VotingMode::CommitReveal`,
+		/// after the commit window and before the end of the motion.
+		///
+		/// Transaction fees will be waived if the vote matches the commitment.
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage mutation `Voting` (codec `O(M)`)
+		///   - 1 storage removal `Commitments` (codec `O(1)`)
+		/// - 1 event
+		/// # </weight>
+		#[pallet::weight((
+			T::WeightInfo::reveal_vote(T::MaxMembers::get()),
+			DispatchClass::Operational
+		))]
+		pub fn reveal_vote(
+			origin: OriginFor<T>,
+			proposal: T::Hash,
+			#[pallet::compact] index: ProposalIndex,
+			approve: bool,
+			salt: [u8; 32],
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let reveal = match T::VotingMode::get() {
+				VotingMode::CommitReveal { reveal, .. } => reveal,
+				VotingMode::Open => Err(Error::<T, I>::WrongVotingMode)?,
+			};
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(now + reveal >= voting.end && now < voting.end, Error::<T, I>::NotRevealWindow);
+
+			let commitment =
+				Self::commitment(&proposal, &who).ok_or(Error::<T, I>::NoCommitment)?;
+			ensure!(
+				commitment == Self::commitment_of(&who, &proposal, index, approve, &salt),
+				Error::<T, I>::CommitmentMismatch
+			);
+			Commitments::<T, I>::remove(&proposal, &who);
+			if approve {
+				voting.ayes.push(who.clone());
+			} else {
+				voting.nays.push(who.clone());
+			}
+			Voting::<T, I>::insert(&proposal, voting);
+			Self::deposit_event(Event::VoteRevealed(who, proposal, approve));
+
+			Ok((Some(T::WeightInfo::reveal_vote(members.len() as u32)), Pays::No).into())
+		}
+
+		/// Abstain from voting on the given proposal. The abstention counts towards the quorum,
+		/// but neither for nor against the proposal.
+		///
+		/// Requires the sender to be a member. With `VotingMode::Open`, a previous vote of the
+		/// member is withdrawn. With `VotingMode::CommitReveal`, a commitment of the member is
+		/// dropped, and members who already revealed their vote can no longer abstain.
+		///
+		/// Transaction fees will be waived if the member takes part in the motion for the first
+		/// time.
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M)` where `M` is members-count (code- and governance-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - 1 storage mutation `Voting` (codec `O(M)`)
+		///   - 1 storage removal `Commitments` (codec `O(1)`)
+		/// - 1 event
+		/// # </weight>
+		#[pallet::weight((
+			T::WeightInfo::abstain(T::MaxMembers::get()),
+			DispatchClass::Operational
+		))]
+		pub fn abstain(
+			origin: OriginFor<T>,
+			proposal: T::Hash,
+			#[pallet::compact] index: ProposalIndex,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+
+			let mut voting = Self::voting(&proposal).ok_or(Error::<T, I>::ProposalMissing)?;
+			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
+			ensure!(!voting.abstentions.contains(&who), Error::<T, I>::DuplicateVote);
+
+			let position_yes = voting.ayes.iter().position(|a| a == &who);
+			let position_no = voting.nays.iter().position(|a| a == &who);
+			let has_voted = position_yes.is_some() || position_no.is_some();
+			// Revealed votes are final.
+			if has_voted && T::VotingMode::get() != VotingMode::Open {
+				Err(Error::<T, I>::DuplicateVote)?
+			}
+			if let Some(pos) = position_yes {
+				voting.ayes.swap_remove(pos);
+			}
+			if let Some(pos) = position_no {
+				voting.nays.swap_remove(pos);
+			}
+			let had_commitment = Commitments::<T, I>::take(&proposal, &who).is_some();
+			voting.abstentions.push(who.clone());
+			Voting::<T, I>::insert(&proposal, voting);
+			Self::deposit_event(Event::Abstained(who, proposal));
+
+			let pays_fee = if has_voted || had_commitment { Pays::Yes } else { Pays::No };
+			Ok((Some(T::WeightInfo::abstain(members.len() as u32)), pays_fee).into())
+		}
+
+		/// Delegate the vote of the sender to another member. In motions the sender doesn't take
+		/// part in, they follow the vote or abstention of `to`, or of the delegate of `to` if
+		/// `to` doesn't take part either, and so on.
+		///
+		/// Requires the sender and `to` to be members. Replaces a previous delegation of the
+		/// sender. Fails if the delegation closes a cycle, or makes any chain of delegations
+		/// through it, including those of members who delegate to the sender, longer than
+		/// `MaxDelegationDepth`.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - `O(M * D)` where:
+		///   - `M` is members-count (code- and governance-bounded)
+		///   - `D` is the length of the chains of delegations through the sender (code-bounded)
+		/// - DB:
+		///   - 1 storage read `Members` (codec `O(M)`)
+		///   - `D` storage reads `Delegations` (codec `O(1)`)
+		///   - up to `M` storage reads `Delegations` (codec `O(1)`), for the delegators of the
+		///     sender
+		///   - 1 storage write `Delegations` (codec `O(1)`)
+		/// - 1 event
+		/// # </weight>
+		#[pallet::weight((
+			T::WeightInfo::delegate(T::MaxMembers::get(), T::MaxDelegationDepth::get()),
+			DispatchClass::Operational
+		))]
+		pub fn delegate(origin: OriginFor<T>, to: T::AccountId) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let members = Self::members();
+			ensure!(members.contains(&who), Error::<T, I>::NotMember);
+			ensure!(members.contains(&to), Error::<T, I>::DelegateNotMember);
+
+			// Existing chains are acyclic and bounded, so this terminates.
+			let max_depth = T::MaxDelegationDepth::get();
+			let mut depth = 0;
+			let mut next = Some(to.clone());
+			while let Some(delegate) = next {
+				ensure!(delegate != who, Error::<T, I>::DelegationCycle);
+				depth += 1;
+				ensure!(depth <= max_depth, Error::<T, I>::DelegationTooDeep);
+				next = Self::delegation(&delegate);
+			}
+
+			// Chains of members who delegate to the sender are extended by the delegation too.
+			let delegations: Vec<_> = Delegations::<T, I>::iter().collect();
+			let mut upstream = 0;
+			let mut delegators = vec![who.clone()];
+			loop {
+				delegators = delegations
+					.iter()
+					.filter(|(_, delegate)| delegators.contains(delegate))
+					.map(|(delegator, _)| delegator.clone())
+					.collect();
+				if delegators.is_empty() {
+					break
+				}
+				upstream += 1;
+				ensure!(depth + upstream <= max_depth, Error::<T, I>::DelegationTooDeep);
+			}
+			Delegations::<T, I>::insert(&who, &to);
+			Self::deposit_event(Event::Delegated(who, to));
+
+			// Every level of the chains through the sender was searched for.
+			let levels = depth - 1 + upstream;
+			Ok(Some(T::WeightInfo::delegate(members.len() as u32, levels)).into())
+		}
+
+		/// Withdraw the delegation of the sender.
+		///
+		/// # <weight>
+		/// ## Weight
+		/// - `O(1)`
+		/// - DB: 1 storage removal `Delegations` (codec `O(1)`)
+		/// - 1 event
+		/// # </weight>
+		#[pallet::weight((T::WeightInfo::undelegate(), DispatchClass::Operational))]
+		pub fn undelegate(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Delegations::<T, I>::take(&who).ok_or(Error::<T, I>::NotDelegating)?;
+			Self::deposit_event(Event::Undelegated(who));
+			Ok(())
+		}
+
 		/// Close a vote that is either approved, disapproved or whose voting period has ended.
 		///
 		/// May be called by any signed account in order to finish voting and close the proposal.
@@ -581,8 +1105,13 @@ decl_module! {
 		/// If called before the end of the voting period it will only close the vote if it is
 		/// has enough votes to be approved or disapproved.
 		///
-		/// If called after the end of the voting period abstentions are counted as rejections
-		/// unless there is a prime member set and the prime member cast an approval.
+		/// Votes are counted by their `VoteWeight`. Members who didn't take part follow their
+		/// delegate, if any.
+		///
+		/// If called after the end of the voting period, the proposal is disapproved if fewer
+		/// members than the `Quorum` voted or abstained. Otherwise the members who didn't take part
+		/// are counted as rejections unless there is a prime member set and the prime member cast
+		/// an approval.
 		///
 		/// If the close operation completes successfully with disapproval, the transaction fee will
 		/// be waived. Otherwise execution of the approved operation will be charged to the caller.
@@ -602,9 +1131,12 @@ decl_module! {
 		///  - 2 storage reads (`Members`: codec `O(M)`, `Prime`: codec `O(1)`)
 		///  - 3 mutations (`Voting`: codec `O(M)`, `ProposalOf`: codec `O(B)`, `Proposals`: codec `O(P2)`)
 		///  - any mutations done while executing `proposal` (`P1`)
+		///  - any mutations done by `OnProposalClosed`
+		///  - any reads done by `VoteWeight`
+		///  - 1 storage read `Delegations` (codec `O(1)`) per member who didn't take part
 		/// - up to 3 events
 		/// # </weight>
-		#[weight = (
+		#[pallet::weight((
 			{
 				let b = *length_bound;
 				let m = T::MaxMembers::get();
@@ -615,25 +1147,42 @@ decl_module! {
 					.max(T::WeightInfo::close_approved(b, m, p2))
 					.max(T::WeightInfo::close_disapproved(m, p2))
 					.saturating_add(p1)
+					.saturating_add(Pallet::<T, I>::max_closing_weight(m))
+					.saturating_add(T::VoteWeight::max_weight(m))
+					.saturating_add(Pallet::<T, I>::max_delegation_weight(m))
 			},
 			DispatchClass::Operational
-		)]
-		fn close(origin,
+		))]
+		pub fn close(
+			origin: OriginFor<T>,
 			proposal_hash: T::Hash,
-			#[compact] index: ProposalIndex,
-			#[compact] proposal_weight_bound: Weight,
-			#[compact] length_bound: u32
+			#[pallet::compact] index: ProposalIndex,
+			#[pallet::compact] proposal_weight_bound: Weight,
+			#[pallet::compact] length_bound: u32,
 		) -> DispatchResultWithPostInfo {
 			let _ = ensure_signed(origin)?;
 
 			let voting = Self::voting(&proposal_hash).ok_or(Error::<T, I>::ProposalMissing)?;
 			ensure!(voting.index == index, Error::<T, I>::WrongIndex);
 
-			let mut no_votes = voting.nays.len() as MemberCount;
-			let mut yes_votes = voting.ayes.len() as MemberCount;
-			let seats = Self::members().len() as MemberCount;
-			let approved = yes_votes >= voting.threshold;
-			let disapproved = seats.saturating_sub(no_votes) < voting.threshold;
+			let members = Self::members();
+			let seats = members.len() as MemberCount;
+			let total = Self::weight_of(&members);
+			let (delegated_ayes, delegated_nays, delegated_abstentions) =
+				Self::delegated_votes(&members, &voting);
+			let mut no_votes =
+				Self::weight_of(&voting.nays).saturating_add(Self::weight_of(&delegated_nays));
+			let mut yes_votes =
+				Self::weight_of(&voting.ayes).saturating_add(Self::weight_of(&delegated_ayes));
+			let abstaining = Self::weight_of(&voting.abstentions)
+				.saturating_add(Self::weight_of(&delegated_abstentions));
+			let participants = yes_votes.saturating_add(no_votes).saturating_add(abstaining);
+			let quorum = T::Quorum::get().mul_ceil(total);
+			let lookup_weight = T::VoteWeight::max_weight(seats)
+				.saturating_add(Self::max_delegation_weight(seats));
+			let approved = yes_votes >= voting.threshold && participants >= quorum;
+			let disapproved =
+				total.saturating_sub(no_votes.saturating_add(abstaining)) < voting.threshold;
 			// Allow (dis-)approving the proposal as soon as there are enough votes.
 			if approved {
 				let (proposal, len) = Self::validate_and_get_proposal(
@@ -641,36 +1190,59 @@ decl_module! {
 					length_bound,
 					proposal_weight_bound,
 				)?;
-				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
+				Self::deposit_event(Event::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
+					Self::do_approve_proposal(total, voting, proposal_hash, proposal);
 				return Ok((
 					Some(T::WeightInfo::close_early_approved(len as u32, seats, proposal_count)
-					.saturating_add(proposal_weight)),
+					.saturating_add(proposal_weight)
+					.saturating_add(lookup_weight)),
 					Pays::Yes,
 				).into());
 
 			} else if disapproved {
-				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
-				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+				Self::deposit_event(Event::Closed(proposal_hash, yes_votes, no_votes));
+				Self::deposit_event(Event::Disapproved(proposal_hash));
+				let (closing_weight, proposal_count) =
+					Self::do_disapprove_proposal(total, proposal_hash);
 				return Ok((
-					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)),
+					Some(T::WeightInfo::close_early_disapproved(seats, proposal_count)
+					.saturating_add(closing_weight)
+					.saturating_add(lookup_weight)),
 					Pays::No,
 				).into());
 			}
 
 			// Only allow actual closing of the proposal after the voting period has ended.
-			ensure!(system::Pallet::<T>::block_number() >= voting.end, Error::<T, I>::TooEarly);
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= voting.end,
+				Error::<T, I>::TooEarly
+			);
+
+			if participants < quorum {
+				Self::deposit_event(Event::Closed(proposal_hash, yes_votes, no_votes));
+				Self::deposit_event(
+					Event::DisapprovedWithoutQuorum(proposal_hash, participants, quorum)
+				);
+				let (closing_weight, proposal_count) =
+					Self::do_disapprove_proposal(total, proposal_hash);
+				return Ok((
+					Some(T::WeightInfo::close_disapproved(seats, proposal_count)
+					.saturating_add(closing_weight)
+					.saturating_add(lookup_weight)),
+					Pays::No,
+				).into());
+			}
 
 			let prime_vote = Self::prime().map(|who| voting.ayes.iter().any(|a| a == &who));
 
 			// default voting strategy.
-			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, seats);
+			let default = T::DefaultVote::default_vote(prime_vote, yes_votes, no_votes, total);
 
-			let abstentions = seats - (yes_votes + no_votes);
+			let absent = total.saturating_sub(participants);
 			match default {
-				true => yes_votes += abstentions,
-				false => no_votes += abstentions,
+				true => yes_votes += absent,
+				false => no_votes += absent,
 			}
 			let approved = yes_votes >= voting.threshold;
 
@@ -680,19 +1252,24 @@ decl_module! {
 					length_bound,
 					proposal_weight_bound,
 				)?;
-				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
+				Self::deposit_event(Event::Closed(proposal_hash, yes_votes, no_votes));
 				let (proposal_weight, proposal_count) =
-					Self::do_approve_proposal(seats, voting, proposal_hash, proposal);
+					Self::do_approve_proposal(total, voting, proposal_hash, proposal);
 				return Ok((
 					Some(T::WeightInfo::close_approved(len as u32, seats, proposal_count)
-					.saturating_add(proposal_weight)),
+					.saturating_add(proposal_weight)
+					.saturating_add(lookup_weight)),
 					Pays::Yes,
 				).into());
 			} else {
-				Self::deposit_event(RawEvent::Closed(proposal_hash, yes_votes, no_votes));
-				let proposal_count = Self::do_disapprove_proposal(proposal_hash);
+				Self::deposit_event(Event::Closed(proposal_hash, yes_votes, no_votes));
+				Self::deposit_event(Event::Disapproved(proposal_hash));
+				let (closing_weight, proposal_count) =
+					Self::do_disapprove_proposal(total, proposal_hash);
 				return Ok((
-					Some(T::WeightInfo::close_disapproved(seats, proposal_count)),
+					Some(T::WeightInfo::close_disapproved(seats, proposal_count)
+					.saturating_add(closing_weight)
+					.saturating_add(lookup_weight)),
 					Pays::No,
 				).into());
 			}
@@ -708,19 +1285,36 @@ decl_module! {
 		/// # <weight>
 		/// Complexity: O(P) where P is the number of max proposals
 		/// DB Weight:
-		/// * Reads: Proposals
+		/// * Reads: Proposals, Members
 		/// * Writes: Voting, Proposals, ProposalOf
+		/// * Any reads done by `VoteWeight`
+		/// * Any mutations done by `OnProposalClosed`
 		/// # </weight>
-		#[weight = T::WeightInfo::disapprove_proposal(T::MaxProposals::get())]
-		fn disapprove_proposal(origin, proposal_hash: T::Hash) -> DispatchResultWithPostInfo {
+		#[pallet::weight(T::WeightInfo::disapprove_proposal(T::MaxProposals::get())
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::VoteWeight::max_weight(T::MaxMembers::get()))
+			.saturating_add(Pallet::<T, I>::max_closing_weight(T::MaxMembers::get())))]
+		pub fn disapprove_proposal(
+			origin: OriginFor<T>,
+			proposal_hash: T::Hash,
+		) -> DispatchResultWithPostInfo {
 			ensure_root(origin)?;
-			let proposal_count = Self::do_disapprove_proposal(proposal_hash);
-			Ok(Some(T::WeightInfo::disapprove_proposal(proposal_count)).into())
+			let members = Self::members();
+			let total = Self::weight_of(&members);
+			Self::deposit_event(Event::Disapproved(proposal_hash));
+			let (closing_weight, proposal_count) =
+				Self::do_disapprove_proposal(total, proposal_hash);
+			Ok(Some(
+				T::WeightInfo::disapprove_proposal(proposal_count)
+					.saturating_add(T::DbWeight::get().reads(1))
+					.saturating_add(T::VoteWeight::max_weight(members.len() as u32))
+					.saturating_add(closing_weight)
+			).into())
 		}
 	}
 }
 
-impl<T: Config<I>, I: Instance> Module<T, I> {
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
 	/// Check whether `who` is a member of the collective.
 	pub fn is_member(who: &T::AccountId) -> bool {
 		// Note: The dispatchables *do not* use this to check membership so make sure
@@ -728,6 +1322,97 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		Self::members().contains(who)
 	}
 
+	/// The commitment of `who` to a secret vote on the motion `index` about `proposal`, to be
+	/// passed to `commit_vote`. It is bound to the member and the motion, so it can't be copied by
+	/// other members or into other motions.
+	pub fn commitment_of(
+		who: &T::AccountId,
+		proposal: &T::Hash,
+		index: ProposalIndex,
+		approve: bool,
+		salt: &[u8; 32],
+	) -> T::Hash {
+		T::Hashing::hash_of(&(who, proposal, index, approve, salt))
+	}
+
+	/// Whether `propose` may execute a proposal right away, condoned by the sender alone.
+	pub fn direct_execution_allowed() -> bool {
+		T::VotingMode::get() == VotingMode::Open &&
+			T::Quorum::get().is_zero() &&
+			!T::VoteWeight::WEIGHTED
+	}
+
+	/// The sum of the vote weights of `who`.
+	fn weight_of(who: &[T::AccountId]) -> MemberCount {
+		who.iter().fold(0, |sum, who| sum.saturating_add(T::VoteWeight::vote_weight(who)))
+	}
+
+	/// The origin of a motion approved with `approvals` out of `total` votes.
+	fn approval_origin(approvals: MemberCount, total: MemberCount) -> RawOrigin<T::AccountId, I> {
+		if T::VoteWeight::WEIGHTED {
+			RawOrigin::WeightedMembers(approvals, total)
+		} else {
+			RawOrigin::Members(approvals, total)
+		}
+	}
+
+	/// The members who didn't take part in the motion, but follow a delegate who did. Returns
+	/// the members who follow an aye, a nay and an abstention respectively.
+	fn delegated_votes(
+		members: &[T::AccountId],
+		voting: &Votes<T::AccountId, T::BlockNumber>,
+	) -> (Vec<T::AccountId>, Vec<T::AccountId>, Vec<T::AccountId>) {
+		let mut delegated = (Vec::new(), Vec::new(), Vec::new());
+		let max_depth = T::MaxDelegationDepth::get();
+		if max_depth == 0 {
+			return delegated
+		}
+		let absent: Vec<_> = members
+			.iter()
+			.filter(|&who| {
+				!voting.ayes.contains(who) &&
+					!voting.nays.contains(who) &&
+					!voting.abstentions.contains(who)
+			})
+			.collect();
+		// Chains of delegations only go on through members who didn't take part.
+		let delegations: BTreeMap<_, _> = absent
+			.iter()
+			.filter_map(|&who| Self::delegation(who).map(|to| (who.clone(), to)))
+			.collect();
+		for who in absent {
+			let mut next = delegations.get(who);
+			for _ in 0..max_depth {
+				match next {
+					Some(delegate) if voting.ayes.contains(delegate) => {
+						delegated.0.push(who.clone());
+						break
+					},
+					Some(delegate) if voting.nays.contains(delegate) => {
+						delegated.1.push(who.clone());
+						break
+					},
+					Some(delegate) if voting.abstentions.contains(delegate) => {
+						delegated.2.push(who.clone());
+						break
+					},
+					Some(delegate) => next = delegations.get(delegate),
+					None => break,
+				}
+			}
+		}
+		delegated
+	}
+
+	/// The maximum weight of looking up the delegations of `members` members when closing a
+	/// motion.
+	fn max_delegation_weight(members: MemberCount) -> Weight {
+		if T::MaxDelegationDepth::get() == 0 {
+			return 0
+		}
+		T::DbWeight::get().reads(members.into())
+	}
+
 	/// Ensure that the right proposal bounds were passed and get the proposal from storage.
 	///
 	/// Checks the length in storage via `storage::read` which adds an extra `size_of::<u32>() == 4`
@@ -763,17 +1448,25 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 	/// Computation and i/o `O(P)` where:
 	/// - `P` is number of active proposals
 	fn do_approve_proposal(
-		seats: MemberCount,
+		total: MemberCount,
 		voting: Votes<T::AccountId, T::BlockNumber>,
 		proposal_hash: T::Hash,
 		proposal: <T as Config<I>>::Proposal,
 	) -> (Weight, u32) {
-		Self::deposit_event(RawEvent::Approved(proposal_hash));
+		Self::deposit_event(Event::Approved(proposal_hash));
+		let origin = Self::approval_origin(voting.threshold, total);
+		// Reported before the execution, which may depend on the votes.
+		let closing_weight = T::OnProposalClosed::on_proposal_closed(
+			origin.clone().into(),
+			&proposal,
+			true,
+			&voting.ayes,
+			&voting.nays,
+		);
 
 		let dispatch_weight = proposal.get_dispatch_info().weight;
-		let origin = RawOrigin::Members(voting.threshold, seats).into();
-		let result = proposal.dispatch(origin);
-		Self::deposit_event(RawEvent::Executed(
+		let result = proposal.dispatch(origin.into());
+		Self::deposit_event(Event::Executed(
 			proposal_hash,
 			result.map(|_| ()).map_err(|e| e.error),
 		));
@@ -781,13 +1474,32 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		let proposal_weight = get_result_weight(result).unwrap_or(dispatch_weight); // P1
 
 		let proposal_count = Self::remove_proposal(proposal_hash);
-		(proposal_weight, proposal_count)
+		(proposal_weight.saturating_add(closing_weight), proposal_count)
+	}
+
+	/// Returns the weight consumed by `OnProposalClosed` and the number of proposals. The caller
+	/// deposits the event saying why the proposal was disapproved. `total` is the total vote
+	/// weight of the members, as for `do_approve_proposal`.
+	fn do_disapprove_proposal(total: MemberCount, proposal_hash: T::Hash) -> (Weight, u32) {
+		let mut closing_weight = T::DbWeight::get().reads(2);
+		if let (Some(proposal), Some(voting)) =
+			(Self::proposal_of(&proposal_hash), Self::voting(&proposal_hash))
+		{
+			closing_weight = closing_weight.saturating_add(T::OnProposalClosed::on_proposal_closed(
+				Self::approval_origin(voting.threshold, total).into(),
+				&proposal,
+				false,
+				&voting.ayes,
+				&voting.nays,
+			));
+		}
+		(closing_weight, Self::remove_proposal(proposal_hash))
 	}
 
-	fn do_disapprove_proposal(proposal_hash: T::Hash) -> u32 {
-		// disapproved
-		Self::deposit_event(RawEvent::Disapproved(proposal_hash));
-		Self::remove_proposal(proposal_hash)
+	/// The maximum weight of reporting a closed motion to `OnProposalClosed`, including reading
+	/// the motion.
+	fn max_closing_weight(members: MemberCount) -> Weight {
+		T::DbWeight::get().reads(2).saturating_add(T::OnProposalClosed::max_weight(members))
 	}
 
 	// Removes a proposal from the pallet, cleaning up votes and the vector of proposals.
@@ -795,6 +1507,8 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 		// remove proposal and vote
 		ProposalOf::<T, I>::remove(&proposal_hash);
 		Voting::<T, I>::remove(&proposal_hash);
+		// Unrevealed commitments are dropped along with the motion.
+		let _ = Commitments::<T, I>::remove_prefix(&proposal_hash, None);
 		let num_proposals = Proposals::<T, I>::mutate(|proposals| {
 			proposals.retain(|h| h != &proposal_hash);
 			proposals.len() + 1 // calculate weight based on original length
@@ -803,7 +1517,7 @@ impl<T: Config<I>, I: Instance> Module<T, I> {
 	}
 }
 
-impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
+impl<T: Config<I>, I: 'static> ChangeMembers<T::AccountId> for Pallet<T, I> {
 	/// Update the members of the collective. Votes are updated and the prime is reset.
 	///
 	/// NOTE: Does not enforce the expected `MaxMembers` limit on the amount of members, but
@@ -820,6 +1534,8 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 	///   - `P` storage mutations for updating the votes (codec `O(M)`)
 	///   - 1 storage write (codec `O(N)`) for storing the new members
 	///   - 1 storage write (codec `O(1)`) for deleting the old prime
+	///   - up to `M` storage reads and removals (codec `O(1)`) for dropping the delegations
+	///     from and to outgoing members
 	/// # </weight>
 	fn change_members_sorted(
 		_incoming: &[T::AccountId],
@@ -850,10 +1566,28 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 						.into_iter()
 						.filter(|i| outgoing.binary_search(i).is_err())
 						.collect();
+					votes.abstentions = votes
+						.abstentions
+						.into_iter()
+						.filter(|i| outgoing.binary_search(i).is_err())
+						.collect();
 					*v = Some(votes);
 				}
 			});
 		}
+		// drop delegations from and to outgoing members.
+		if !outgoing.is_empty() {
+			let dropped: Vec<_> = Delegations::<T, I>::iter()
+				.filter(|(who, to)| {
+					outgoing.binary_search(who).is_ok() || outgoing.binary_search(to).is_ok()
+				})
+				.map(|(who, _)| who)
+				.collect();
+			for who in dropped {
+				Delegations::<T, I>::remove(&who);
+				Self::deposit_event(Event::Undelegated(who));
+			}
+		}
 		Members::<T, I>::put(new);
 		Prime::<T, I>::kill();
 	}
@@ -867,7 +1601,7 @@ impl<T: Config<I>, I: Instance> ChangeMembers<T::AccountId> for Module<T, I> {
 	}
 }
 
-impl<T: Config<I>, I: Instance> InitializeMembers<T::AccountId> for Module<T, I> {
+impl<T: Config<I>, I: 'static> InitializeMembers<T::AccountId> for Pallet<T, I> {
 	fn initialize_members(members: &[T::AccountId]) {
 		if !members.is_empty() {
 			assert!(<Members<T, I>>::get().is_empty(), "Members are already initialized!");
@@ -876,8 +1610,17 @@ impl<T: Config<I>, I: Instance> InitializeMembers<T::AccountId> for Module<T, I>
 	}
 }
 
+impl<T: Config<I>, I: 'static> SortedMembers<T::AccountId> for Pallet<T, I> {
+	fn sorted_members() -> Vec<T::AccountId> {
+		Self::members()
+	}
+}
+
 /// Ensure that the origin `o` represents at least `n` members. Returns `Ok` or an `Err`
 /// otherwise.
+///
+/// `RawOrigin::WeightedMembers` is rejected: it counts vote weight rather than members, so a few
+/// heavy members could pass for many. Use the proportion origins for weighted collectives.
 pub fn ensure_members<OuterOrigin, AccountId, I>(
 	o: OuterOrigin,
 	n: MemberCount,
@@ -891,9 +1634,9 @@ where
 	}
 }
 
-pub struct EnsureMember<AccountId, I = DefaultInstance>(
-	sp_std::marker::PhantomData<(AccountId, I)>,
-);
+/// Ensure that the origin is a single member, as dispatched by `execute`. Motions approved by
+/// weighted votes are never from a single member, whatever the vote weight behind them.
+pub struct EnsureMember<AccountId, I = ()>(PhantomData<(AccountId, I)>);
 impl<
 		O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
 		AccountId: Default,
@@ -914,9 +1657,9 @@ impl<
 	}
 }
 
-pub struct EnsureMembers<N: U32, AccountId, I = DefaultInstance>(
-	sp_std::marker::PhantomData<(N, AccountId, I)>,
-);
+/// Ensure that the origin represents at least `N` members. Like `ensure_members`, it rejects
+/// `RawOrigin::WeightedMembers`, which counts vote weight rather than members.
+pub struct EnsureMembers<N: U32, AccountId, I = ()>(PhantomData<(N, AccountId, I)>);
 impl<
 		O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
 		N: U32,
@@ -938,8 +1681,8 @@ impl<
 	}
 }
 
-pub struct EnsureProportionMoreThan<N: U32, D: U32, AccountId, I = DefaultInstance>(
-	sp_std::marker::PhantomData<(N, D, AccountId, I)>,
+pub struct EnsureProportionMoreThan<N: U32, D: U32, AccountId, I = ()>(
+	PhantomData<(N, D, AccountId, I)>,
 );
 impl<
 		O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
@@ -952,7 +1695,9 @@ impl<
 	type Success = ();
 	fn try_origin(o: O) -> Result<Self::Success, O> {
 		o.into().and_then(|o| match o {
-			RawOrigin::Members(n, m) if n * D::VALUE > N::VALUE * m => Ok(()),
+			RawOrigin::Members(n, m) | RawOrigin::WeightedMembers(n, m)
+				if n * D::VALUE > N::VALUE * m =>
+				Ok(()),
 			r => Err(O::from(r)),
 		})
 	}
@@ -963,8 +1708,8 @@ impl<
 	}
 }
 
-pub struct EnsureProportionAtLeast<N: U32, D: U32, AccountId, I = DefaultInstance>(
-	sp_std::marker::PhantomData<(N, D, AccountId, I)>,
+pub struct EnsureProportionAtLeast<N: U32, D: U32, AccountId, I = ()>(
+	PhantomData<(N, D, AccountId, I)>,
 );
 impl<
 		O: Into<Result<RawOrigin<AccountId, I>, O>> + From<RawOrigin<AccountId, I>>,
@@ -977,7 +1722,9 @@ impl<
 	type Success = ();
 	fn try_origin(o: O) -> Result<Self::Success, O> {
 		o.into().and_then(|o| match o {
-			RawOrigin::Members(n, m) if n * D::VALUE >= N::VALUE * m => Ok(()),
+			RawOrigin::Members(n, m) | RawOrigin::WeightedMembers(n, m)
+				if n * D::VALUE >= N::VALUE * m =>
+				Ok(()),
 			r => Err(O::from(r)),
 		})
 	}
@@ -992,7 +1739,8 @@ impl<
 mod tests {
 	use super::*;
 	use crate as collective;
-	use frame_support::{assert_noop, assert_ok, parameter_types, Hashable};
+	use crate::Event as CollectiveEvent;
+	use frame_support::{assert_noop, assert_ok, parameter_types, weights::Pays, Hashable};
 	use frame_system::{self as system, EventRecord, Phase};
 	use hex_literal::hex;
 	use sp_core::H256;
@@ -1005,8 +1753,11 @@ mod tests {
 	parameter_types! {
 		pub const BlockHashCount: u64 = 250;
 		pub const MotionDuration: u64 = 3;
+		pub const SecretVoting: VotingMode<u64> = VotingMode::CommitReveal { commit: 2, reveal: 2 };
+		pub const MajorityQuorum: Perbill = Perbill::from_percent(60);
 		pub const MaxProposals: u32 = 100;
 		pub const MaxMembers: u32 = 100;
+		pub const MaxDelegationDepth: u32 = 2;
 		pub BlockWeights: frame_system::limits::BlockWeights =
 			frame_system::limits::BlockWeights::simple_max(1024);
 	}
@@ -1035,6 +1786,47 @@ mod tests {
 		type SS58Prefix = ();
 		type OnSetCode = ();
 	}
+	thread_local! {
+		static CLOSED: std::cell::RefCell<Vec<(Call, bool, Vec<u64>, Vec<u64>)>> =
+			Default::default();
+		static CLOSING_ORIGINS: std::cell::RefCell<Vec<Option<RawOrigin<u64, Instance1>>>> =
+			Default::default();
+	}
+	/// Records the closed motions.
+	pub struct RecordClosed;
+	impl OnProposalClosed<u64, Call, Origin> for RecordClosed {
+		fn on_proposal_closed(
+			origin: Origin,
+			proposal: &Call,
+			approved: bool,
+			ayes: &[u64],
+			nays: &[u64],
+		) -> Weight {
+			CLOSED.with(|c| {
+				c.borrow_mut().push((proposal.clone(), approved, ayes.to_vec(), nays.to_vec()))
+			});
+			let origin: Result<RawOrigin<u64, Instance1>, Origin> = origin.into();
+			CLOSING_ORIGINS.with(|c| c.borrow_mut().push(origin.ok()));
+			0
+		}
+
+		fn max_weight(_: MemberCount) -> Weight {
+			0
+		}
+	}
+	fn closed() -> Vec<(Call, bool, Vec<u64>, Vec<u64>)> {
+		CLOSED.with(|c| c.borrow().clone())
+	}
+	fn closing_origins() -> Vec<Option<RawOrigin<u64, Instance1>>> {
+		CLOSING_ORIGINS.with(|c| c.borrow().clone())
+	}
+	/// Accounts below 10.
+	pub struct BelowTen;
+	impl Contains<u64> for BelowTen {
+		fn contains(who: &u64) -> bool {
+			*who < 10
+		}
+	}
 	impl Config<Instance1> for Test {
 		type Origin = Origin;
 		type Proposal = Call;
@@ -1043,6 +1835,12 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type MembershipFilter = frame_support::traits::Everything;
+		type OnProposalClosed = RecordClosed;
+		type VotingMode = OpenVoting;
+		type Quorum = ();
+		type VoteWeight = ();
+		type MaxDelegationDepth = MaxDelegationDepth;
 		type WeightInfo = ();
 	}
 	impl Config<Instance2> for Test {
@@ -1053,9 +1851,15 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = MoreThanMajorityThenPrimeDefaultVote;
+		type MembershipFilter = BelowTen;
+		type OnProposalClosed = ();
+		type VotingMode = OpenVoting;
+		type Quorum = MajorityQuorum;
+		type VoteWeight = ();
+		type MaxDelegationDepth = ();
 		type WeightInfo = ();
 	}
-	impl Config for Test {
+	impl Config<Instance3> for Test {
 		type Origin = Origin;
 		type Proposal = Call;
 		type Event = Event;
@@ -1063,6 +1867,55 @@ mod tests {
 		type MaxProposals = MaxProposals;
 		type MaxMembers = MaxMembers;
 		type DefaultVote = PrimeDefaultVote;
+		type MembershipFilter = frame_support::traits::Everything;
+		type OnProposalClosed = ();
+		type VotingMode = SecretVoting;
+		type Quorum = ();
+		type VoteWeight = ();
+		type MaxDelegationDepth = ();
+		type WeightInfo = ();
+	}
+	/// Members vote with the weight of their account id.
+	pub struct AccountIdWeight;
+	impl VoteWeight<u64> for AccountIdWeight {
+		fn vote_weight(who: &u64) -> MemberCount {
+			*who as MemberCount
+		}
+
+		fn max_weight(_: MemberCount) -> Weight {
+			0
+		}
+	}
+	impl Config<Instance4> for Test {
+		type Origin = Origin;
+		type Proposal = Call;
+		type Event = Event;
+		type MotionDuration = MotionDuration;
+		type MaxProposals = MaxProposals;
+		type MaxMembers = MaxMembers;
+		type DefaultVote = PrimeDefaultVote;
+		type MembershipFilter = frame_support::traits::Everything;
+		type OnProposalClosed = ();
+		type VotingMode = OpenVoting;
+		type Quorum = ();
+		type VoteWeight = AccountIdWeight;
+		type MaxDelegationDepth = ();
+		type WeightInfo = ();
+	}
+	impl Config for Test {
+		type Origin = Origin;
+		type Proposal = Call;
+		type Event = Event;
+		type MotionDuration = MotionDuration;
+		type MaxProposals = MaxProposals;
+		type MaxMembers = MaxMembers;
+		type DefaultVote = PrimeDefaultVote;
+		type MembershipFilter = frame_support::traits::Everything;
+		type OnProposalClosed = ();
+		type VotingMode = OpenVoting;
+		type Quorum = ();
+		type VoteWeight = ();
+		type MaxDelegationDepth = MaxDelegationDepth;
 		type WeightInfo = ();
 	}
 
@@ -1078,6 +1931,8 @@ mod tests {
 			System: system::{Pallet, Call, Event<T>},
 			Collective: collective::<Instance1>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 			CollectiveMajority: collective::<Instance2>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
+			CollectiveSecret: collective::<Instance3>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
+			CollectiveWeighted: collective::<Instance4>::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 			DefaultCollective: collective::{Pallet, Call, Event<T>, Origin<T>, Config<T>},
 		}
 	);
@@ -1092,6 +1947,14 @@ mod tests {
 				members: vec![1, 2, 3, 4, 5],
 				phantom: Default::default(),
 			},
+			collective_secret: collective::GenesisConfig {
+				members: vec![1, 2, 3],
+				phantom: Default::default(),
+			},
+			collective_weighted: collective::GenesisConfig {
+				members: vec![1, 2, 3],
+				phantom: Default::default(),
+			},
 			default_collective: Default::default(),
 		}
 		.build_storage()
@@ -1156,11 +2019,11 @@ mod tests {
 			assert_eq!(
 				System::events(),
 				vec![
-					record(Event::Collective(RawEvent::Proposed(1, 0, hash.clone(), 3))),
-					record(Event::Collective(RawEvent::Voted(1, hash.clone(), true, 1, 0))),
-					record(Event::Collective(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
-					record(Event::Collective(RawEvent::Closed(hash.clone(), 2, 1))),
-					record(Event::Collective(RawEvent::Disapproved(hash.clone())))
+					record(Event::Collective(CollectiveEvent::Proposed(1, 0, hash.clone(), 3))),
+					record(Event::Collective(CollectiveEvent::Voted(1, hash.clone(), true, 1, 0))),
+					record(Event::Collective(CollectiveEvent::Voted(2, hash.clone(), true, 2, 0))),
+					record(Event::Collective(CollectiveEvent::Closed(hash.clone(), 2, 1))),
+					record(Event::Collective(CollectiveEvent::Disapproved(hash.clone())))
 				]
 			);
 		});
@@ -1269,11 +2132,11 @@ mod tests {
 			assert_eq!(
 				System::events(),
 				vec![
-					record(Event::Collective(RawEvent::Proposed(1, 0, hash.clone(), 3))),
-					record(Event::Collective(RawEvent::Voted(1, hash.clone(), true, 1, 0))),
-					record(Event::Collective(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
-					record(Event::Collective(RawEvent::Closed(hash.clone(), 2, 1))),
-					record(Event::Collective(RawEvent::Disapproved(hash.clone())))
+					record(Event::Collective(CollectiveEvent::Proposed(1, 0, hash.clone(), 3))),
+					record(Event::Collective(CollectiveEvent::Voted(1, hash.clone(), true, 1, 0))),
+					record(Event::Collective(CollectiveEvent::Voted(2, hash.clone(), true, 2, 0))),
+					record(Event::Collective(CollectiveEvent::Closed(hash.clone(), 2, 1))),
+					record(Event::Collective(CollectiveEvent::Disapproved(hash.clone())))
 				]
 			);
 		});
@@ -1316,12 +2179,12 @@ mod tests {
 			assert_eq!(
 				System::events(),
 				vec![
-					record(Event::Collective(RawEvent::Proposed(1, 0, hash.clone(), 3))),
-					record(Event::Collective(RawEvent::Voted(1, hash.clone(), true, 1, 0))),
-					record(Event::Collective(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
-					record(Event::Collective(RawEvent::Closed(hash.clone(), 3, 0))),
-					record(Event::Collective(RawEvent::Approved(hash.clone()))),
-					record(Event::Collective(RawEvent::Executed(
+					record(Event::Collective(CollectiveEvent::Proposed(1, 0, hash.clone(), 3))),
+					record(Event::Collective(CollectiveEvent::Voted(1, hash.clone(), true, 1, 0))),
+					record(Event::Collective(CollectiveEvent::Voted(2, hash.clone(), true, 2, 0))),
+					record(Event::Collective(CollectiveEvent::Closed(hash.clone(), 3, 0))),
+					record(Event::Collective(CollectiveEvent::Approved(hash.clone()))),
+					record(Event::Collective(CollectiveEvent::Executed(
 						hash.clone(),
 						Err(DispatchError::BadOrigin)
 					)))
@@ -1368,13 +2231,13 @@ mod tests {
 			assert_eq!(
 				System::events(),
 				vec![
-					record(Event::CollectiveMajority(RawEvent::Proposed(1, 0, hash.clone(), 5))),
-					record(Event::CollectiveMajority(RawEvent::Voted(1, hash.clone(), true, 1, 0))),
-					record(Event::CollectiveMajority(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
-					record(Event::CollectiveMajority(RawEvent::Voted(3, hash.clone(), true, 3, 0))),
-					record(Event::CollectiveMajority(RawEvent::Closed(hash.clone(), 5, 0))),
-					record(Event::CollectiveMajority(RawEvent::Approved(hash.clone()))),
-					record(Event::CollectiveMajority(RawEvent::Executed(
+					record(Event::CollectiveMajority(CollectiveEvent::Proposed(1, 0, hash.clone(), 5))),
+					record(Event::CollectiveMajority(CollectiveEvent::Voted(1, hash.clone(), true, 1, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::Voted(2, hash.clone(), true, 2, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::Voted(3, hash.clone(), true, 3, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::Closed(hash.clone(), 5, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::Approved(hash.clone()))),
+					record(Event::CollectiveMajority(CollectiveEvent::Executed(
 						hash.clone(),
 						Err(DispatchError::BadOrigin)
 					)))
@@ -1400,12 +2263,26 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 3,
+					ayes: vec![1, 2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 			Collective::change_members_sorted(&[4], &[1], &[2, 3, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 3,
+					ayes: vec![2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 
 			let proposal = make_proposal(69);
@@ -1421,13 +2298,658 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes {
+					index: 1,
+					threshold: 2,
+					ayes: vec![2],
+					nays: vec![3],
+					abstentions: vec![],
+					end,
+				})
 			);
 			Collective::change_members_sorted(&[], &[3], &[2, 4]);
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes {
+					index: 1,
+					threshold: 2,
+					ayes: vec![2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn closed_motions_report_their_votes() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(Collective::vote(Origin::signed(1), hash, 0, true));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, false));
+			assert!(closed().is_empty());
+
+			// Abstentions count as nays, but are not reported as votes.
+			System::set_block_number(4);
+			assert_ok!(Collective::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len));
+			assert_eq!(closed(), vec![(proposal.clone(), false, vec![1], vec![2])]);
+
+			let proposal = make_proposal(43);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(Collective::vote(Origin::signed(1), hash, 1, true));
+			assert_ok!(Collective::vote(Origin::signed(3), hash, 1, true));
+			assert_ok!(Collective::close(Origin::signed(4), hash, 1, proposal_weight, proposal_len));
+			assert_eq!(closed()[1], (proposal, true, vec![1, 3], vec![]));
+
+			// Motions disapproved by root are reported too.
+			let proposal = make_proposal(44);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 2, false));
+			assert_ok!(Collective::disapprove_proposal(Origin::root(), hash));
+			assert_eq!(closed()[2], (proposal, false, vec![], vec![2]));
+
+			// Each is reported with the origin of the collective it was voted in.
+			assert_eq!(closing_origins(), vec![Some(RawOrigin::Members(2, 3)); 3]);
+		});
+	}
+
+	#[test]
+	fn commit_reveal_only_counts_revealed_votes() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let aye = |who| CollectiveSecret::commitment_of(&who, &hash, 0, true, &[1; 32]);
+			let nay = |who| CollectiveSecret::commitment_of(&who, &hash, 0, false, &[2; 32]);
+			assert_ok!(CollectiveSecret::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_noop!(
+				CollectiveSecret::vote(Origin::signed(1), hash, 0, true),
+				Error::<Test, Instance3>::WrongVotingMode
+			);
+
+			// Blocks 1 and 2 are the commit window.
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, nay(1)));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, aye(1)));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(2), hash, 0, aye(2)));
+			System::set_block_number(2);
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(3), hash, 0, nay(3)));
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]),
+				Error::<Test, Instance3>::NotRevealWindow
+			);
+
+			// Blocks 3 and 4 are the reveal window.
+			System::set_block_number(3);
+			assert_noop!(
+				CollectiveSecret::commit_vote(Origin::signed(4), hash, 0, aye(4)),
+				Error::<Test, Instance3>::NotMember
+			);
+			assert_noop!(
+				CollectiveSecret::commit_vote(Origin::signed(2), hash, 0, nay(2)),
+				Error::<Test, Instance3>::CommitWindowOver
+			);
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, false, [1; 32]),
+				Error::<Test, Instance3>::CommitmentMismatch
+			);
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]));
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]),
+				Error::<Test, Instance3>::NoCommitment
+			);
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(3), hash, 0, false, [2; 32]));
+
+			// 2 never reveals their aye, so the abstention counts against the motion.
+			System::set_block_number(5);
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(2), hash, 0, true, [1; 32]),
+				Error::<Test, Instance3>::NotRevealWindow
+			);
+			assert_ok!(CollectiveSecret::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			assert_eq!(CollectiveSecret::commitment(hash, 2), None);
+
+			let record =
+				|event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(
+				System::events(),
+				vec![
+					record(Event::CollectiveSecret(CollectiveEvent::Proposed(1, 0, hash, 2))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteCommitted(1, hash))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteCommitted(1, hash))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteCommitted(2, hash))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteCommitted(3, hash))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteRevealed(1, hash, true))),
+					record(Event::CollectiveSecret(CollectiveEvent::VoteRevealed(3, hash, false))),
+					record(Event::CollectiveSecret(CollectiveEvent::Closed(hash, 1, 2))),
+					record(Event::CollectiveSecret(CollectiveEvent::Disapproved(hash))),
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn commit_reveal_motions_pass_once_enough_ayes_are_revealed() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let aye = |who| CollectiveSecret::commitment_of(&who, &hash, 0, true, &[1; 32]);
+			assert_ok!(CollectiveSecret::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, aye(1)));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(2), hash, 0, aye(2)));
+			// Commitments alone don't count.
+			assert_noop!(
+				CollectiveSecret::close(Origin::signed(4), hash, 0, proposal_weight, proposal_len),
+				Error::<Test, Instance3>::TooEarly
+			);
+
+			System::set_block_number(3);
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]));
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(2), hash, 0, true, [1; 32]));
+			assert_ok!(CollectiveSecret::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			System::assert_last_event(Event::CollectiveSecret(CollectiveEvent::Executed(
+				hash,
+				Err(DispatchError::BadOrigin)
+			)));
+		});
+	}
+
+	#[test]
+	fn copied_commitments_cannot_be_revealed() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let aye = CollectiveSecret::commitment_of(&1, &hash, 0, true, &[1; 32]);
+			assert_ok!(CollectiveSecret::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, aye));
+			// 2 copies the commitment of 1, without knowing the vote behind it.
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(2), hash, 0, aye));
+
+			System::set_block_number(3);
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]));
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(2), hash, 0, true, [1; 32]),
+				Error::<Test, Instance3>::CommitmentMismatch
+			);
+		});
+	}
+
+	#[test]
+	fn secret_collectives_refuse_direct_execution() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			assert!(!CollectiveSecret::direct_execution_allowed());
+			assert_noop!(
+				CollectiveSecret::propose(
+					Origin::signed(1),
+					1,
+					Box::new(proposal.clone()),
+					proposal_len
+				),
+				Error::<Test, Instance3>::MotionRequired
+			);
+			assert_ok!(CollectiveSecret::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_eq!(CollectiveSecret::proposals().len(), 1);
+		});
+	}
+
+	#[test]
+	fn open_collectives_reject_commitments() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let aye = Collective::commitment_of(&1, &hash, 0, true, &[1; 32]);
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal), proposal_len));
+			assert_noop!(
+				Collective::commit_vote(Origin::signed(1), hash, 0, aye),
+				Error::<Test, Instance1>::WrongVotingMode
+			);
+			assert_noop!(
+				Collective::reveal_vote(Origin::signed(1), hash, 0, true, [1; 32]),
+				Error::<Test, Instance1>::WrongVotingMode
+			);
+		});
+	}
+
+	#[test]
+	fn abstaining_withdraws_open_votes() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let end = 4;
+			assert_ok!(Collective::propose(Origin::signed(1), 2, Box::new(proposal), proposal_len));
+			let abstain_rval = Collective::abstain(Origin::signed(2), hash, 0);
+			assert_eq!(abstain_rval.unwrap().pays_fee, Pays::No);
+			assert_ok!(Collective::vote(Origin::signed(1), hash, 0, true));
+			let abstain_rval = Collective::abstain(Origin::signed(1), hash, 0);
+			assert_eq!(abstain_rval.unwrap().pays_fee, Pays::Yes);
+			System::assert_last_event(Event::Collective(CollectiveEvent::Abstained(1, hash)));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![],
+					nays: vec![],
+					abstentions: vec![2, 1],
+					end,
+				})
+			);
+			assert_noop!(
+				Collective::abstain(Origin::signed(1), hash, 0),
+				Error::<Test, Instance1>::DuplicateVote
+			);
+			assert_noop!(
+				Collective::abstain(Origin::signed(4), hash, 0),
+				Error::<Test, Instance1>::NotMember
+			);
+
+			// Voting again replaces the abstention.
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, false));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![],
+					nays: vec![2],
+					abstentions: vec![1],
+					end,
+				})
+			);
+		});
+	}
+
+	#[test]
+	fn abstaining_drops_secret_commitments() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let salt = [1; 32];
+			let aye = |who| CollectiveSecret::commitment_of(&who, &hash, 0, true, &salt);
+			assert_ok!(CollectiveSecret::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, aye(1)));
+			assert_ok!(CollectiveSecret::commit_vote(Origin::signed(2), hash, 0, aye(2)));
+			assert_ok!(CollectiveSecret::abstain(Origin::signed(1), hash, 0));
+			assert_eq!(CollectiveSecret::commitment(hash, 1), None);
+			assert_noop!(
+				CollectiveSecret::commit_vote(Origin::signed(1), hash, 0, aye(1)),
+				Error::<Test, Instance3>::DuplicateVote
+			);
+
+			// Revealed votes are final.
+			System::set_block_number(3);
+			assert_ok!(CollectiveSecret::reveal_vote(Origin::signed(2), hash, 0, true, salt));
+			assert_noop!(
+				CollectiveSecret::abstain(Origin::signed(2), hash, 0),
+				Error::<Test, Instance3>::DuplicateVote
+			);
+			assert_noop!(
+				CollectiveSecret::reveal_vote(Origin::signed(1), hash, 0, true, salt),
+				Error::<Test, Instance3>::NoCommitment
+			);
+		});
+	}
+
+	#[test]
+	fn quorum_collectives_refuse_direct_execution() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			assert!(Collective::direct_execution_allowed());
+			assert!(!CollectiveMajority::direct_execution_allowed());
+			// A single member is short of the quorum of 60%.
+			assert_noop!(
+				CollectiveMajority::propose(Origin::signed(1), 1, Box::new(proposal), proposal_len),
+				Error::<Test, Instance2>::MotionRequired
+			);
+		});
+	}
+
+	#[test]
+	fn motions_need_quorum_to_pass() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(CollectiveMajority::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveMajority::vote(Origin::signed(1), hash, 0, true));
+			assert_ok!(CollectiveMajority::vote(Origin::signed(2), hash, 0, true));
+			// Two out of five members are short of the quorum of 60%.
+			assert_noop!(
+				CollectiveMajority::close(
+					Origin::signed(4),
+					hash,
+					0,
+					proposal_weight,
+					proposal_len
+				),
+				Error::<Test, Instance2>::TooEarly
+			);
+
+			assert_ok!(CollectiveMajority::abstain(Origin::signed(3), hash, 0));
+			assert_ok!(CollectiveMajority::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			let record =
+				|event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(
+				System::events()[4..],
+				[
+					record(Event::CollectiveMajority(CollectiveEvent::Closed(hash, 2, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::Approved(hash))),
+					record(Event::CollectiveMajority(CollectiveEvent::Executed(
+						hash,
+						Err(DispatchError::BadOrigin)
+					))),
+				]
+			);
+		});
+	}
+
+	#[test]
+	fn motions_without_quorum_are_disapproved() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(CollectiveMajority::set_members(
+				Origin::root(),
+				vec![1, 2, 3, 4, 5],
+				Some(1),
+				MaxMembers::get()
+			));
+			assert_ok!(CollectiveMajority::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveMajority::vote(Origin::signed(1), hash, 0, true));
+			assert_ok!(CollectiveMajority::abstain(Origin::signed(2), hash, 0));
+
+			// The prime voted aye, but it doesn't stand in for anyone without quorum.
+			System::set_block_number(4);
+			assert_ok!(CollectiveMajority::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			let record =
+				|event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(
+				System::events()[3..],
+				[
+					record(Event::CollectiveMajority(CollectiveEvent::Closed(hash, 1, 0))),
+					record(Event::CollectiveMajority(CollectiveEvent::DisapprovedWithoutQuorum(
+						hash, 2, 3
+					))),
+				]
+			);
+			assert_eq!(*CollectiveMajority::proposals(), Vec::<H256>::new());
+		});
+	}
+
+	#[test]
+	fn abstentions_can_disapprove_motions_early() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::propose(Origin::signed(1), 3, Box::new(proposal), proposal_len));
+			assert_ok!(Collective::abstain(Origin::signed(2), hash, 0));
+			assert_ok!(Collective::close(
+				Origin::signed(2),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			System::assert_last_event(Event::Collective(CollectiveEvent::Disapproved(hash)));
+			assert_eq!(closed(), vec![(make_proposal(42), false, vec![], vec![])]);
+		});
+	}
+
+	#[test]
+	fn migration_to_v3_moves_the_storage() {
+		use crate::migrations::v3::OldVotes;
+		use frame_support::{storage::migration::put_storage_value, traits::StorageVersion};
+
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			let old = OldVotes::<u64, u64> {
+				index: 0,
+				threshold: 2,
+				ayes: vec![4],
+				nays: vec![5],
+				end: 4,
+			};
+			let prefix = b"Instance1Collective";
+			put_storage_value(prefix, b"Proposals", &[], vec![hash]);
+			put_storage_value(prefix, b"ProposalOf", &hash.encode(), proposal.clone());
+			put_storage_value(prefix, b"Voting", &hash.encode(), old);
+			put_storage_value(prefix, b"Members", &[], vec![4u64, 5]);
+			put_storage_value(prefix, b"Prime", &[], 4u64);
+			StorageVersion::new(0).put::<Collective>();
+
+			migrations::v3::migrate::<Test, Instance1, _>("Instance1Collective");
+
+			assert_eq!(*Collective::proposals(), vec![hash]);
+			assert_eq!(Collective::proposal_of(&hash), Some(proposal));
+			assert_eq!(
+				Collective::voting(&hash),
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![4],
+					nays: vec![5],
+					abstentions: vec![],
+					end: 4,
+				})
 			);
+			assert_eq!(Collective::members(), vec![4, 5]);
+			assert_eq!(Collective::prime(), Some(4));
+			assert_eq!(StorageVersion::get::<Collective>(), 3);
+		});
+	}
+
+	#[test]
+	fn weighted_votes_decide_motions() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(CollectiveWeighted::propose(
+				Origin::signed(1),
+				4,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveWeighted::vote(Origin::signed(3), hash, 0, true));
+			// Three of the six votes are short of the threshold.
+			assert_noop!(
+				CollectiveWeighted::close(
+					Origin::signed(3),
+					hash,
+					0,
+					proposal_weight,
+					proposal_len
+				),
+				Error::<Test, Instance4>::TooEarly
+			);
+			assert_ok!(CollectiveWeighted::vote(Origin::signed(1), hash, 0, true));
+			assert_ok!(CollectiveWeighted::close(
+				Origin::signed(3),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			let record =
+				|event| EventRecord { phase: Phase::Initialization, event, topics: vec![] };
+			assert_eq!(
+				System::events()[3..],
+				[
+					record(Event::CollectiveWeighted(CollectiveEvent::Closed(hash, 4, 0))),
+					record(Event::CollectiveWeighted(CollectiveEvent::Approved(hash))),
+					record(Event::CollectiveWeighted(CollectiveEvent::Executed(
+						hash,
+						Err(DispatchError::BadOrigin)
+					))),
+				]
+			);
+
+			// The nay of the heaviest member leaves too little weight to reach the threshold.
+			let proposal = make_proposal(43);
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(CollectiveWeighted::propose(
+				Origin::signed(1),
+				4,
+				Box::new(proposal),
+				proposal_len
+			));
+			assert_ok!(CollectiveWeighted::vote(Origin::signed(3), hash, 1, false));
+			assert_ok!(CollectiveWeighted::close(
+				Origin::signed(3),
+				hash,
+				1,
+				proposal_weight,
+				proposal_len
+			));
+			System::assert_last_event(Event::CollectiveWeighted(
+				CollectiveEvent::Disapproved(hash),
+			));
+		});
+	}
+
+	#[test]
+	fn weighted_origins_count_for_proportions() {
+		use sp_core::u32_trait::{_1, _2};
+
+		let weighted = |n, d| Origin::from(RawOrigin::<u64, Instance4>::WeightedMembers(n, d));
+		type AtLeastHalf = EnsureProportionAtLeast<_1, _2, u64, Instance4>;
+		type MoreThanHalf = EnsureProportionMoreThan<_1, _2, u64, Instance4>;
+		assert!(AtLeastHalf::try_origin(weighted(3, 6)).is_ok());
+		assert!(AtLeastHalf::try_origin(weighted(2, 6)).is_err());
+		assert!(MoreThanHalf::try_origin(weighted(4, 6)).is_ok());
+		assert!(MoreThanHalf::try_origin(weighted(3, 6)).is_err());
+		// Vote weight is no head count.
+		assert!(EnsureMembers::<_2, u64, Instance4>::try_origin(weighted(6, 6)).is_err());
+		assert!(ensure_members::<_, u64, Instance4>(weighted(6, 6), 2).is_err());
+		assert!(EnsureMember::<u64, Instance4>::try_origin(weighted(6, 6)).is_err());
+	}
+
+	#[test]
+	fn weighted_collectives_refuse_direct_execution() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			assert!(!CollectiveWeighted::direct_execution_allowed());
+			// Even the heaviest member can't act on behalf of the collective alone.
+			assert_noop!(
+				CollectiveWeighted::propose(Origin::signed(3), 1, Box::new(proposal), proposal_len),
+				Error::<Test, Instance4>::MotionRequired
+			);
+		});
+	}
+
+	#[test]
+	fn set_members_only_accepts_filtered_accounts() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				CollectiveMajority::set_members(Origin::root(), vec![1, 10], None, MaxMembers::get()),
+				Error::<Test, Instance2>::MemberNotAllowed
+			);
+			assert_eq!(CollectiveMajority::members(), vec![1, 2, 3, 4, 5]);
+			assert_ok!(CollectiveMajority::set_members(
+				Origin::root(),
+				vec![9, 1],
+				None,
+				MaxMembers::get()
+			));
+			assert_eq!(CollectiveMajority::members(), vec![1, 9]);
 		});
 	}
 
@@ -1448,7 +2970,14 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(2), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![1, 2], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 3,
+					ayes: vec![1, 2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 			assert_ok!(Collective::set_members(
 				Origin::root(),
@@ -1458,7 +2987,14 @@ mod tests {
 			));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![2], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 3,
+					ayes: vec![2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 
 			let proposal = make_proposal(69);
@@ -1474,7 +3010,14 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(3), hash.clone(), 1, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![3], end })
+				Some(Votes {
+					index: 1,
+					threshold: 2,
+					ayes: vec![2],
+					nays: vec![3],
+					abstentions: vec![],
+					end,
+				})
 			);
 			assert_ok!(Collective::set_members(
 				Origin::root(),
@@ -1484,7 +3027,14 @@ mod tests {
 			));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 1, threshold: 2, ayes: vec![2], nays: vec![], end })
+				Some(Votes {
+					index: 1,
+					threshold: 2,
+					ayes: vec![2],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 		});
 	}
@@ -1506,14 +3056,21 @@ mod tests {
 			assert_eq!(Collective::proposal_of(&hash), Some(proposal));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 3, ayes: vec![], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 3,
+					ayes: vec![],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 
 			assert_eq!(
 				System::events(),
 				vec![EventRecord {
 					phase: Phase::Initialization,
-					event: Event::Collective(RawEvent::Proposed(
+					event: Event::Collective(CollectiveEvent::Proposed(
 						1,
 						0,
 						hex!["68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"]
@@ -1659,13 +3216,27 @@ mod tests {
 			// Initially there a no votes when the motion is proposed.
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 			// Cast first aye vote.
 			assert_ok!(Collective::vote(Origin::signed(1), hash.clone(), 0, true));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![1], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![1],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 			// Try to cast a duplicate aye vote.
 			assert_noop!(
@@ -1676,7 +3247,14 @@ mod tests {
 			assert_ok!(Collective::vote(Origin::signed(1), hash.clone(), 0, false));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![1], end })
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![],
+					nays: vec![1],
+					abstentions: vec![],
+					end,
+				})
 			);
 			// Try to cast a duplicate nay vote.
 			assert_noop!(
@@ -1689,7 +3267,7 @@ mod tests {
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Proposed(
+						event: Event::Collective(CollectiveEvent::Proposed(
 							1,
 							0,
 							hex![
@@ -1702,7 +3280,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							1,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1716,7 +3294,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							1,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1748,7 +3326,14 @@ mod tests {
 			));
 			assert_eq!(
 				Collective::voting(&hash),
-				Some(Votes { index: 0, threshold: 2, ayes: vec![], nays: vec![], end })
+				Some(Votes {
+					index: 0,
+					threshold: 2,
+					ayes: vec![],
+					nays: vec![],
+					abstentions: vec![],
+					end,
+				})
 			);
 
 			// For the motion, acc 2's first vote, expecting Ok with Pays::No.
@@ -1861,7 +3446,7 @@ mod tests {
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Proposed(
+						event: Event::Collective(CollectiveEvent::Proposed(
 							1,
 							0,
 							hex![
@@ -1874,7 +3459,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							1,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1888,7 +3473,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							2,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1902,7 +3487,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Closed(
+						event: Event::Collective(CollectiveEvent::Closed(
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
 							]
@@ -1914,7 +3499,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Disapproved(
+						event: Event::Collective(CollectiveEvent::Disapproved(
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
 							]
@@ -1955,7 +3540,7 @@ mod tests {
 				vec![
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Proposed(
+						event: Event::Collective(CollectiveEvent::Proposed(
 							1,
 							0,
 							hex![
@@ -1968,7 +3553,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							1,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1982,7 +3567,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Voted(
+						event: Event::Collective(CollectiveEvent::Voted(
 							2,
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
@@ -1996,7 +3581,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Closed(
+						event: Event::Collective(CollectiveEvent::Closed(
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
 							]
@@ -2008,7 +3593,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Approved(
+						event: Event::Collective(CollectiveEvent::Approved(
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
 							]
@@ -2018,7 +3603,7 @@ mod tests {
 					},
 					EventRecord {
 						phase: Phase::Initialization,
-						event: Event::Collective(RawEvent::Executed(
+						event: Event::Collective(CollectiveEvent::Executed(
 							hex![
 								"68eea8f20b542ec656c6ac2d10435ae3bd1729efc34d1354ab85af840aad2d35"
 							]
@@ -2049,7 +3634,7 @@ mod tests {
 			));
 			assert_eq!(
 				System::events()[0],
-				record(Event::Collective(RawEvent::Proposed(1, 0, hash.clone(), 3)))
+				record(Event::Collective(CollectiveEvent::Proposed(1, 0, hash.clone(), 3)))
 			);
 
 			// Closing the motion too early is not possible because it has neither
@@ -2080,11 +3665,11 @@ mod tests {
 			// Events show that the close ended in a disapproval.
 			assert_eq!(
 				System::events()[1],
-				record(Event::Collective(RawEvent::Closed(hash.clone(), 0, 3)))
+				record(Event::Collective(CollectiveEvent::Closed(hash.clone(), 0, 3)))
 			);
 			assert_eq!(
 				System::events()[2],
-				record(Event::Collective(RawEvent::Disapproved(hash.clone())))
+				record(Event::Collective(CollectiveEvent::Disapproved(hash.clone())))
 			);
 		})
 	}
@@ -2146,15 +3731,154 @@ mod tests {
 			assert_eq!(
 				System::events(),
 				vec![
-					record(Event::Collective(RawEvent::Proposed(1, 0, hash.clone(), 2))),
-					record(Event::Collective(RawEvent::Voted(1, hash.clone(), true, 1, 0))),
-					record(Event::Collective(RawEvent::Voted(2, hash.clone(), true, 2, 0))),
-					record(Event::Collective(RawEvent::Disapproved(hash.clone()))),
+					record(Event::Collective(CollectiveEvent::Proposed(1, 0, hash.clone(), 2))),
+					record(Event::Collective(CollectiveEvent::Voted(1, hash.clone(), true, 1, 0))),
+					record(Event::Collective(CollectiveEvent::Voted(2, hash.clone(), true, 2, 0))),
+					record(Event::Collective(CollectiveEvent::Disapproved(hash.clone()))),
 				]
 			);
 		})
 	}
 
+	#[test]
+	fn delegated_votes_count_when_closing() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::delegate(Origin::signed(1), 2));
+			System::assert_last_event(Event::Collective(CollectiveEvent::Delegated(1, 2)));
+			assert_ok!(Collective::propose(
+				Origin::signed(1),
+				2,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(Collective::vote(Origin::signed(2), hash, 0, true));
+			// 1 follows the vote of 2, which approves the motion early.
+			assert_ok!(Collective::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			System::assert_has_event(Event::Collective(CollectiveEvent::Closed(hash, 2, 0)));
+			System::assert_has_event(Event::Collective(CollectiveEvent::Approved(hash)));
+			// Only the votes cast by members themselves are reported.
+			assert_eq!(closed(), vec![(proposal, true, vec![2], vec![])]);
+		});
+	}
+
+	#[test]
+	fn delegation_chains_are_followed_when_closing() {
+		new_test_ext().execute_with(|| {
+			let proposal = make_proposal(42);
+			let proposal_len: u32 = proposal.using_encoded(|p| p.len() as u32);
+			let proposal_weight = proposal.get_dispatch_info().weight;
+			let hash = BlakeTwo256::hash_of(&proposal);
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3, 4], None, 3));
+			assert_ok!(Collective::delegate(Origin::signed(2), 3));
+			assert_ok!(Collective::delegate(Origin::signed(4), 2));
+			assert_ok!(Collective::delegate(Origin::signed(1), 3));
+			assert_ok!(Collective::propose(
+				Origin::signed(1),
+				3,
+				Box::new(proposal.clone()),
+				proposal_len
+			));
+			assert_ok!(Collective::vote(Origin::signed(3), hash, 0, false));
+			// 1 votes instead of following 3, and 4 follows 3 through 2.
+			assert_ok!(Collective::vote(Origin::signed(1), hash, 0, true));
+			assert_ok!(Collective::close(
+				Origin::signed(4),
+				hash,
+				0,
+				proposal_weight,
+				proposal_len
+			));
+			System::assert_has_event(Event::Collective(CollectiveEvent::Closed(hash, 1, 3)));
+			System::assert_last_event(Event::Collective(CollectiveEvent::Disapproved(hash)));
+		});
+	}
+
+	#[test]
+	fn delegation_rejects_cycles_and_long_chains() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3, 4], None, 3));
+			assert_noop!(
+				Collective::delegate(Origin::signed(1), 1),
+				Error::<Test, Instance1>::DelegationCycle
+			);
+			assert_noop!(
+				Collective::delegate(Origin::signed(1), 5),
+				Error::<Test, Instance1>::DelegateNotMember
+			);
+			assert_noop!(
+				Collective::delegate(Origin::signed(5), 1),
+				Error::<Test, Instance1>::NotMember
+			);
+			assert_ok!(Collective::delegate(Origin::signed(1), 2));
+			assert_ok!(Collective::delegate(Origin::signed(2), 3));
+			assert_noop!(
+				Collective::delegate(Origin::signed(3), 1),
+				Error::<Test, Instance1>::DelegationCycle
+			);
+			assert_noop!(
+				Collective::delegate(Origin::signed(4), 1),
+				Error::<Test, Instance1>::DelegationTooDeep
+			);
+			assert_ok!(Collective::undelegate(Origin::signed(2)));
+			System::assert_last_event(Event::Collective(CollectiveEvent::Undelegated(2)));
+			assert_noop!(
+				Collective::undelegate(Origin::signed(2)),
+				Error::<Test, Instance1>::NotDelegating
+			);
+			assert_ok!(Collective::delegate(Origin::signed(4), 1));
+			// Collectives without a `MaxDelegationDepth` don't allow delegation.
+			assert_noop!(
+				CollectiveMajority::delegate(Origin::signed(1), 2),
+				Error::<Test, Instance2>::DelegationTooDeep
+			);
+		});
+	}
+
+	#[test]
+	fn delegation_rejects_links_deepening_chains_of_delegators() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3, 4], None, 3));
+			assert_ok!(Collective::delegate(Origin::signed(1), 2));
+			assert_ok!(Collective::delegate(Origin::signed(2), 3));
+			// The chain from 1 would be 1 -> 2 -> 3 -> 4.
+			assert_noop!(
+				Collective::delegate(Origin::signed(3), 4),
+				Error::<Test, Instance1>::DelegationTooDeep
+			);
+			assert_ok!(Collective::delegate(Origin::signed(4), 3));
+			assert_noop!(
+				Collective::delegate(Origin::signed(3), 2),
+				Error::<Test, Instance1>::DelegationCycle
+			);
+		});
+	}
+
+	#[test]
+	fn delegations_of_outgoing_members_are_dropped() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Collective::delegate(Origin::signed(1), 2));
+			assert_ok!(Collective::delegate(Origin::signed(2), 3));
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 3, 4], None, 3));
+			assert_eq!(Collective::delegation(1), None);
+			assert_eq!(Collective::delegation(2), None);
+			System::assert_has_event(Event::Collective(CollectiveEvent::Undelegated(1)));
+			System::assert_has_event(Event::Collective(CollectiveEvent::Undelegated(2)));
+			assert_ok!(Collective::delegate(Origin::signed(1), 3));
+			assert_ok!(Collective::set_members(Origin::root(), vec![1, 2, 3], None, 3));
+			assert_eq!(Collective::delegation(1), Some(3));
+		});
+	}
+
 	#[test]
 	#[should_panic(expected = "Members cannot contain duplicate accounts.")]
 	fn genesis_build_panics_with_duplicate_members() {
@@ -0,0 +1,97 @@
+//! Storage migrations for the collective pallet.
+
+/// Migration from the `decl_storage` pallet to the FRAME v2 pallet.
+pub mod v3 {
+	use crate::{Config, MemberCount, Pallet, ProposalIndex, Votes, Voting};
+	use codec::{Decode, Encode};
+	use frame_support::{
+		storage::migration,
+		traits::{Get, PalletInfoAccess, StorageVersion},
+		weights::Weight,
+	};
+	use sp_runtime::RuntimeDebug;
+	use sp_std::vec::Vec;
+
+	/// Storage releases of the `decl_storage` pallet, kept in its `StorageVersion` value.
+	#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+	pub enum Releases {
+		/// Votes without abstentions.
+		V1,
+		/// Votes with a list of abstentions.
+		V2,
+	}
+
+	/// Votes as stored before `Releases::V2`.
+	#[derive(Encode, Decode)]
+	pub struct OldVotes<AccountId, BlockNumber> {
+		pub index: ProposalIndex,
+		pub threshold: MemberCount,
+		pub ayes: Vec<AccountId>,
+		pub nays: Vec<AccountId>,
+		pub end: BlockNumber,
+	}
+
+	/// Moves the storage of an instance of the `decl_storage` pallet, e.g. `Instance1Collective`,
+	/// to the prefix of the pallet in the runtime, e.g. `Council`. Votes from before
+	/// `Releases::V2` are given an empty list of abstentions on the way.
+	///
+	/// Must run before the hooks of the pallet, e.g. as the custom `OnRuntimeUpgrade` of the
+	/// executive. Returns the weight of the storage accesses, which grows with the number of
+	/// moved keys and translated votes.
+	pub fn migrate<T: Config<I>, I: 'static, N: AsRef<str>>(old_pallet_name: N) -> Weight {
+		if StorageVersion::get::<Pallet<T, I>>() >= StorageVersion::new(3) {
+			return T::DbWeight::get().reads(1)
+		}
+		let mut reads: Weight = 2;
+		let mut writes: Weight = 2;
+
+		let old_pallet_name = old_pallet_name.as_ref();
+		let new_pallet_name = <Pallet<T, I> as PalletInfoAccess>::name();
+		if old_pallet_name != new_pallet_name {
+			// Every key is looked up to count it, then read, written under the new prefix and
+			// cleared.
+			let keys = stored_keys(old_pallet_name.as_bytes());
+			reads += 3 * keys;
+			writes += 2 * keys;
+			migration::move_pallet(old_pallet_name.as_bytes(), new_pallet_name.as_bytes());
+		}
+
+		let release = migration::take_storage_value::<Releases>(
+			new_pallet_name.as_bytes(),
+			b"StorageVersion",
+			&[],
+		);
+		if release != Some(Releases::V2) {
+			Voting::<T, I>::translate::<OldVotes<T::AccountId, T::BlockNumber>, _>(|_, old| {
+				reads += 1;
+				writes += 1;
+				Some(Votes {
+					index: old.index,
+					threshold: old.threshold,
+					ayes: old.ayes,
+					nays: old.nays,
+					abstentions: Vec::new(),
+					end: old.end,
+				})
+			});
+		}
+
+		StorageVersion::new(3).put::<Pallet<T, I>>();
+		T::DbWeight::get().reads_writes(reads, writes)
+	}
+
+	/// The number of keys stored under the prefix of the pallet `pallet_name`.
+	fn stored_keys(pallet_name: &[u8]) -> Weight {
+		let prefix = sp_io::hashing::twox_128(pallet_name);
+		let mut keys = 0;
+		let mut key = prefix.to_vec();
+		while let Some(next) = sp_io::storage::next_key(&key) {
+			if !next.starts_with(&prefix) {
+				break
+			}
+			keys += 1;
+			key = next;
+		}
+		keys
+	}
+}
@@ -50,6 +50,11 @@ pub trait WeightInfo {
 	fn propose_execute(b: u32, m: u32, ) -> Weight;
 	fn propose_proposed(b: u32, m: u32, p: u32, ) -> Weight;
 	fn vote(m: u32, ) -> Weight;
+	fn commit_vote(m: u32, ) -> Weight;
+	fn reveal_vote(m: u32, ) -> Weight;
+	fn abstain(m: u32, ) -> Weight;
+	fn delegate(m: u32, d: u32, ) -> Weight;
+	fn undelegate() -> Weight;
 	fn close_early_disapproved(m: u32, p: u32, ) -> Weight;
 	fn close_early_approved(b: u32, m: u32, p: u32, ) -> Weight;
 	fn close_disapproved(m: u32, p: u32, ) -> Weight;
@@ -121,6 +126,45 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Not benchmarked yet, the execution time is a conservative estimate.
+	fn commit_vote(m: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((180_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Not benchmarked yet, the execution time is a conservative estimate.
+	fn reveal_vote(m: u32, ) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((230_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Not benchmarked yet, the execution time is a conservative estimate.
+	fn abstain(m: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Not benchmarked yet, the execution time is a conservative estimate. Every level of the
+	// chains through the sender searches the delegations of all members.
+	fn delegate(m: u32, d: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((104_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((3_125_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((104_000 as Weight).saturating_mul(m as Weight).saturating_mul(d as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Not benchmarked yet, the execution time is a conservative estimate.
+	fn undelegate() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
@@ -254,6 +298,39 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	fn commit_vote(m: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((180_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn reveal_vote(m: u32, ) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((230_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn abstain(m: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((226_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	fn delegate(m: u32, d: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((104_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((3_125_000 as Weight).saturating_mul(d as Weight))
+			.saturating_add((104_000 as Weight).saturating_mul(m as Weight).saturating_mul(d as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(d as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn undelegate() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	// Storage: Instance1Collective Voting (r:1 w:1)
 	// Storage: Instance1Collective Members (r:1 w:0)
 	// Storage: Instance1Collective Proposals (r:1 w:1)
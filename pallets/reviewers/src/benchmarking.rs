@@ -0,0 +1,69 @@
+//! Benchmarking setup for pallet-reviewers
+
+use super::*;
+
+use crate::Pallet as Reviewers;
+use frame_benchmarking::{account, benchmarks, impl_benchmark_test_suite, whitelisted_caller};
+use frame_support::traits::{Currency, EnsureOrigin, Get};
+use frame_system::RawOrigin;
+use sp_runtime::traits::Bounded;
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// An applicant with the longest possible credentials and fields.
+fn applicant<T: Config>() -> Result<T::AccountId, &'static str> {
+	let who: T::AccountId = account("reviewer", 0, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	let fields: Vec<FieldId> = (0..T::MaxFields::get()).collect();
+	Reviewers::<T>::apply(
+		RawOrigin::Signed(who.clone()).into(),
+		vec![0; T::MaxCidLength::get() as usize],
+		fields,
+	)?;
+	Ok(who)
+}
+
+benchmarks! {
+	apply {
+		let n in 1 .. T::MaxCidLength::get();
+		let f in 1 .. T::MaxFields::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+		// Fields in reverse, so that they have to be sorted.
+		let fields: Vec<FieldId> = (0..f).rev().collect();
+	}: _(RawOrigin::Signed(caller.clone()), vec![0; n as usize], fields)
+	verify {
+		assert!(Reviewers::<T>::reviewer(&caller).is_some());
+	}
+
+	withdraw {
+		let who = applicant::<T>()?;
+	}: _(RawOrigin::Signed(who.clone()))
+	verify {
+		assert!(Reviewers::<T>::reviewer(&who).is_none());
+	}
+
+	approve {
+		let who = applicant::<T>()?;
+		let origin = T::AttestOrigin::successful_origin();
+	}: {
+		Reviewers::<T>::approve(origin, who.clone())?;
+	}
+	verify {
+		assert!(Reviewers::<T>::is_approved_in(&who, 0));
+	}
+
+	revoke {
+		let who = applicant::<T>()?;
+		Reviewers::<T>::approve(T::AttestOrigin::successful_origin(), who.clone())?;
+		let origin = T::AttestOrigin::successful_origin();
+	}: {
+		Reviewers::<T>::revoke(origin, who.clone())?;
+	}
+	verify {
+		assert!(Reviewers::<T>::reviewer(&who).is_none());
+	}
+}
+
+impl_benchmark_test_suite!(Reviewers, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,349 @@
+//! # Reviewers Pallet
+//!
+//! A registry of the accounts qualified to review content.
+//!
+//! Accounts apply with `apply`, giving the IPFS CID of their credentials and the fields of
+//! expertise they want to review in, and reserving `ApplicationDeposit`. `AttestOrigin`, e.g. a
+//! passed motion of the Board, checks the credentials off-chain and then approves the application
+//! with `approve`, or rejects or revokes it at any time with `revoke`. Reviewers can withdraw
+//! their own application with `withdraw`. The deposit is returned once the reviewer leaves the
+//! registry.
+//!
+//! Other pallets and the runtime restrict membership with `ApprovedInField`, which contains the
+//! approved reviewers of a single field. `OnRemoved` is told about reviewers leaving the registry,
+//! so that they can be removed from the collectives they serve in right away.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+use frame_support::weights::Weight;
+
+/// Handler for reviewers leaving the registry.
+pub trait OnReviewerRemoved<AccountId> {
+	/// Called when `who` was revoked or withdrew from the registry.
+	///
+	/// Returns the weight consumed.
+	fn on_reviewer_removed(who: &AccountId) -> Weight;
+
+	/// The maximum weight consumed by `on_reviewer_removed`.
+	fn max_weight() -> Weight;
+}
+
+impl<AccountId> OnReviewerRemoved<AccountId> for () {
+	fn on_reviewer_removed(_who: &AccountId) -> Weight {
+		0
+	}
+
+	fn max_weight() -> Weight {
+		0
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{OnReviewerRemoved, WeightInfo};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Contains, Currency, ReservableCurrency, SortedMembers},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::{convert::TryInto, vec::Vec};
+
+	/// Id of a field of expertise. What each id stands for is up to the runtime.
+	pub type FieldId = u32;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Origin allowed to approve and revoke reviewers.
+		type AttestOrigin: EnsureOrigin<Self::Origin>;
+		/// The currency the application deposit is reserved in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+		/// Deposit reserved for an application, returned once the reviewer leaves the registry.
+		#[pallet::constant]
+		type ApplicationDeposit: Get<BalanceOf<Self>>;
+		/// Handler for reviewers leaving the registry, e.g. removing them from their collectives.
+		type OnRemoved: OnReviewerRemoved<Self::AccountId>;
+		/// Maximum length of the CID of the credentials.
+		#[pallet::constant]
+		type MaxCidLength: Get<u32>;
+		/// Maximum number of fields of expertise of a reviewer.
+		#[pallet::constant]
+		type MaxFields: Get<u32>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T>(_);
+
+	#[derive(Encode, Decode, Clone, Copy, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	/// Where a reviewer is in the attestation of their credentials.
+	pub enum ReviewerStatus {
+		/// The reviewer applied and waits for the credentials to be attested.
+		Applied,
+		/// The credentials were attested, the reviewer may review in their fields.
+		Approved,
+	}
+
+	pub type ReviewerOf<T> = Reviewer<<T as Config>::MaxCidLength, <T as Config>::MaxFields>;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	/// An account in the registry.
+	pub struct Reviewer<CidLimit: Get<u32>, FieldLimit: Get<u32>> {
+		/// The IPFS CID of the document describing the credentials of the reviewer.
+		pub credentials_cid: BoundedVec<u8, CidLimit>,
+		/// The fields of expertise of the reviewer, sorted.
+		pub fields: BoundedVec<FieldId, FieldLimit>,
+		/// Whether the credentials were attested.
+		pub status: ReviewerStatus,
+	}
+
+	impl<CidLimit: Get<u32>, FieldLimit: Get<u32>> Reviewer<CidLimit, FieldLimit> {
+		/// Whether the reviewer is approved to review in `field`.
+		pub fn is_approved_in(&self, field: FieldId) -> bool {
+			self.status == ReviewerStatus::Approved && self.fields.binary_search(&field).is_ok()
+		}
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn reviewer)]
+	/// Applied and approved reviewers.
+	pub type Reviewers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, ReviewerOf<T>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn deposit)]
+	/// Deposits reserved by reviewers who applied. Reviewers approved at genesis have none.
+	pub type Deposits<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// Reviewers approved at genesis, with the CID of their credentials and their fields.
+		pub reviewers: Vec<(T::AccountId, Vec<u8>, Vec<FieldId>)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { reviewers: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (who, credentials_cid, fields) in &self.reviewers {
+				let reviewer = Pallet::<T>::new_reviewer(
+					credentials_cid.clone(),
+					fields.clone(),
+					ReviewerStatus::Approved,
+				)
+				.expect("Genesis reviewers must be within the bounds of the registry.");
+				assert!(
+					!Reviewers::<T>::contains_key(who),
+					"Genesis reviewers cannot contain duplicate accounts."
+				);
+				Reviewers::<T>::insert(who, reviewer);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account applied to be a reviewer. \[reviewer\]
+		Applied(T::AccountId),
+		/// The credentials of a reviewer were attested. \[reviewer\]
+		Approved(T::AccountId),
+		/// The approval or application of a reviewer was revoked. \[reviewer\]
+		Revoked(T::AccountId),
+		/// A reviewer withdrew from the registry. \[reviewer\]
+		Withdrawn(T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account already applied.
+		AlreadyApplied,
+		/// The account is not in the registry.
+		NotApplied,
+		/// The reviewer is already approved.
+		AlreadyApproved,
+		/// The CID of the credentials is longer than `MaxCidLength`.
+		CidTooLong,
+		/// An application needs at least one field of expertise.
+		NoFields,
+		/// An application can have at most `MaxFields` fields of expertise.
+		TooManyFields,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		#[pallet::weight(T::WeightInfo::apply(credentials_cid.len() as u32, fields.len() as u32))]
+		/// Applies to be a reviewer in the given fields of expertise, reserving
+		/// `ApplicationDeposit` from the caller.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The applying account.
+		/// * `credentials_cid` - The IPFS CID of the document describing the credentials.
+		/// * `fields` - The fields of expertise the account wants to review in.
+		pub fn apply(
+			origin: OriginFor<T>,
+			credentials_cid: Vec<u8>,
+			fields: Vec<FieldId>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Reviewers::<T>::contains_key(&who), Error::<T>::AlreadyApplied);
+			let reviewer = Self::new_reviewer(credentials_cid, fields, ReviewerStatus::Applied)?;
+			let deposit = T::ApplicationDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Deposits::<T>::insert(&who, deposit);
+			Reviewers::<T>::insert(&who, reviewer);
+			Self::deposit_event(Event::Applied(who));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::withdraw().saturating_add(T::OnRemoved::max_weight()))]
+		/// Removes the application or approval of the caller from the registry and returns the
+		/// deposit.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The reviewer.
+		pub fn withdraw(origin: OriginFor<T>) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let removed_weight = Self::remove(&who)?;
+			Self::deposit_event(Event::Withdrawn(who));
+			Ok(Some(T::WeightInfo::withdraw().saturating_add(removed_weight)).into())
+		}
+
+		#[pallet::weight(T::WeightInfo::approve())]
+		/// Approves the application of a reviewer once the credentials were attested.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the attestation, e.g. a passed motion of the Board.
+		/// * `who` - The applying account.
+		pub fn approve(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::AttestOrigin::ensure_origin(origin)?;
+			Reviewers::<T>::try_mutate(&who, |reviewer| -> DispatchResult {
+				let reviewer = reviewer.as_mut().ok_or(Error::<T>::NotApplied)?;
+				ensure!(reviewer.status == ReviewerStatus::Applied, Error::<T>::AlreadyApproved);
+				reviewer.status = ReviewerStatus::Approved;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::Approved(who));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::revoke().saturating_add(T::OnRemoved::max_weight()))]
+		/// Removes a reviewer from the registry, whether approved or not, and returns the deposit.
+		///
+		/// `OnRemoved` removes the reviewer from the collectives they are in right away.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the revocation, e.g. a passed motion of the Board.
+		/// * `who` - The reviewer.
+		pub fn revoke(origin: OriginFor<T>, who: T::AccountId) -> DispatchResultWithPostInfo {
+			T::AttestOrigin::ensure_origin(origin)?;
+			let removed_weight = Self::remove(&who)?;
+			Self::deposit_event(Event::Revoked(who));
+			Ok(Some(T::WeightInfo::revoke().saturating_add(removed_weight)).into())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `who` is an approved reviewer in `field`.
+		pub fn is_approved_in(who: &T::AccountId, field: FieldId) -> bool {
+			Reviewers::<T>::get(who).map_or(false, |reviewer| reviewer.is_approved_in(field))
+		}
+
+		/// Removes `who` from the registry, returns their deposit and tells `OnRemoved`. Returns
+		/// the weight consumed by `OnRemoved`.
+		fn remove(who: &T::AccountId) -> Result<Weight, DispatchError> {
+			Reviewers::<T>::take(who).ok_or(Error::<T>::NotApplied)?;
+			if let Some(deposit) = Deposits::<T>::take(who) {
+				T::Currency::unreserve(who, deposit);
+			}
+			Ok(T::OnRemoved::on_reviewer_removed(who))
+		}
+
+		/// Builds a reviewer from unbounded input, sorting and deduplicating the fields.
+		fn new_reviewer(
+			credentials_cid: Vec<u8>,
+			mut fields: Vec<FieldId>,
+			status: ReviewerStatus,
+		) -> Result<ReviewerOf<T>, Error<T>> {
+			fields.sort_unstable();
+			fields.dedup();
+			ensure!(!fields.is_empty(), Error::<T>::NoFields);
+			Ok(Reviewer {
+				credentials_cid: credentials_cid.try_into().map_err(|_| Error::<T>::CidTooLong)?,
+				fields: fields.try_into().map_err(|_| Error::<T>::TooManyFields)?,
+				status,
+			})
+		}
+	}
+
+	/// The approved reviewers of the field `Field`.
+	pub struct ApprovedInField<T, Field>(PhantomData<(T, Field)>);
+
+	impl<T: Config, Field: Get<FieldId>> Contains<T::AccountId> for ApprovedInField<T, Field> {
+		fn contains(who: &T::AccountId) -> bool {
+			Pallet::<T>::is_approved_in(who, Field::get())
+		}
+	}
+
+	impl<T: Config, Field: Get<FieldId>> SortedMembers<T::AccountId> for ApprovedInField<T, Field> {
+		/// Iterates the whole registry, only meant for off-chain use.
+		fn sorted_members() -> Vec<T::AccountId> {
+			let mut members: Vec<T::AccountId> = Reviewers::<T>::iter()
+				.filter(|(_, reviewer)| reviewer.is_approved_in(Field::get()))
+				.map(|(who, _)| who)
+				.collect();
+			members.sort();
+			members
+		}
+
+		fn contains(who: &T::AccountId) -> bool {
+			Pallet::<T>::is_approved_in(who, Field::get())
+		}
+
+		#[cfg(feature = "runtime-benchmarks")]
+		fn add(who: &T::AccountId) {
+			let reviewer = Pallet::<T>::new_reviewer(
+				Vec::new(),
+				sp_std::vec![Field::get()],
+				ReviewerStatus::Approved,
+			);
+			if let Ok(reviewer) = reviewer {
+				Reviewers::<T>::insert(who, reviewer);
+			}
+		}
+	}
+}
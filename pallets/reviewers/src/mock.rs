@@ -0,0 +1,128 @@
+use crate as pallet_reviewers;
+use frame_support::{parameter_types, weights::Weight};
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+use std::cell::RefCell;
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		Reviewers: pallet_reviewers::{Pallet, Call, Storage, Event<T>, Config<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+thread_local! {
+	static REMOVED: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+/// Records the reviewers who left the registry.
+pub struct RecordRemoved;
+
+impl crate::OnReviewerRemoved<u64> for RecordRemoved {
+	fn on_reviewer_removed(who: &u64) -> Weight {
+		REMOVED.with(|removed| removed.borrow_mut().push(*who));
+		0
+	}
+
+	fn max_weight() -> Weight {
+		0
+	}
+}
+
+/// The reviewers who left the registry so far, in order.
+pub fn removed() -> Vec<u64> {
+	REMOVED.with(|removed| removed.borrow().clone())
+}
+
+parameter_types! {
+	pub const ApplicationDeposit: u64 = 10;
+	pub const MaxCidLength: u32 = 8;
+	pub const MaxFields: u32 = 3;
+	pub const Physics: u32 = 0;
+	pub const Medicine: u32 = 1;
+}
+
+impl pallet_reviewers::Config for Test {
+	type Event = Event;
+	type AttestOrigin = frame_system::EnsureRoot<u64>;
+	type Currency = Balances;
+	type ApplicationDeposit = ApplicationDeposit;
+	type OnRemoved = RecordRemoved;
+	type MaxCidLength = MaxCidLength;
+	type MaxFields = MaxFields;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(1, 100), (2, 100), (3, 100), (4, 5)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	pallet_reviewers::GenesisConfig::<Test> { reviewers: vec![(1, b"cred".to_vec(), vec![0])] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
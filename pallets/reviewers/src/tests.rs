@@ -0,0 +1,129 @@
+use crate::{mock::*, ApprovedInField, Error, ReviewerStatus};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::DispatchError,
+	traits::{Contains, SortedMembers},
+};
+
+#[test]
+fn genesis_approves_reviewers() {
+	new_test_ext().execute_with(|| {
+		let reviewer = Reviewers::reviewer(1).unwrap();
+		assert_eq!(reviewer.credentials_cid.into_inner(), b"cred".to_vec());
+		assert_eq!(reviewer.status, ReviewerStatus::Approved);
+		assert!(Reviewers::is_approved_in(&1, 0));
+		assert!(!Reviewers::is_approved_in(&1, 1));
+	});
+}
+
+#[test]
+fn applications_are_validated() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Reviewers::apply(Origin::signed(2), b"too long!".to_vec(), vec![0]),
+			Error::<Test>::CidTooLong
+		);
+		assert_noop!(
+			Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![]),
+			Error::<Test>::NoFields
+		);
+		assert_noop!(
+			Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![0, 1, 2, 3]),
+			Error::<Test>::TooManyFields
+		);
+		assert_noop!(
+			Reviewers::apply(Origin::signed(1), b"cred".to_vec(), vec![0]),
+			Error::<Test>::AlreadyApplied
+		);
+
+		// Duplicate fields only count once.
+		assert_ok!(Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![2, 0, 2, 1]));
+		System::assert_last_event(Event::Reviewers(crate::Event::Applied(2)));
+		let reviewer = Reviewers::reviewer(2).unwrap();
+		assert_eq!(reviewer.fields.into_inner(), vec![0, 1, 2]);
+		assert_eq!(reviewer.status, ReviewerStatus::Applied);
+		assert!(!Reviewers::is_approved_in(&2, 0));
+	});
+}
+
+#[test]
+fn attesting_origin_approves_and_revokes() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![1]));
+		assert_noop!(Reviewers::approve(Origin::signed(1), 2), DispatchError::BadOrigin);
+		assert_noop!(Reviewers::approve(Origin::root(), 3), Error::<Test>::NotApplied);
+
+		assert_ok!(Reviewers::approve(Origin::root(), 2));
+		System::assert_last_event(Event::Reviewers(crate::Event::Approved(2)));
+		assert!(Reviewers::is_approved_in(&2, 1));
+		assert_noop!(Reviewers::approve(Origin::root(), 2), Error::<Test>::AlreadyApproved);
+
+		assert_noop!(Reviewers::revoke(Origin::signed(1), 2), DispatchError::BadOrigin);
+		assert_ok!(Reviewers::revoke(Origin::root(), 2));
+		System::assert_last_event(Event::Reviewers(crate::Event::Revoked(2)));
+		assert!(Reviewers::reviewer(2).is_none());
+		assert_noop!(Reviewers::revoke(Origin::root(), 2), Error::<Test>::NotApplied);
+	});
+}
+
+#[test]
+fn reviewers_withdraw() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Reviewers::withdraw(Origin::signed(2)), Error::<Test>::NotApplied);
+		assert_ok!(Reviewers::withdraw(Origin::signed(1)));
+		System::assert_last_event(Event::Reviewers(crate::Event::Withdrawn(1)));
+		assert!(Reviewers::reviewer(1).is_none());
+	});
+}
+
+#[test]
+fn applications_reserve_a_deposit_until_the_reviewer_leaves() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Reviewers::apply(Origin::signed(4), b"cred".to_vec(), vec![0]),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![0]));
+		assert_ok!(Reviewers::apply(Origin::signed(3), b"cred".to_vec(), vec![0]));
+		assert_eq!(Reviewers::deposit(2), Some(10));
+		assert_eq!(Balances::reserved_balance(2), 10);
+
+		// Rejected applications get their deposit back, as do reviewers who withdraw.
+		assert_ok!(Reviewers::revoke(Origin::root(), 2));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Reviewers::deposit(2), None);
+		assert_ok!(Reviewers::withdraw(Origin::signed(3)));
+		assert_eq!(Balances::reserved_balance(3), 0);
+		assert_eq!(Balances::free_balance(3), 100);
+	});
+}
+
+#[test]
+fn leaving_reviewers_are_handed_to_on_removed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![0]));
+		assert_ok!(Reviewers::approve(Origin::root(), 2));
+		assert_noop!(Reviewers::revoke(Origin::root(), 3), Error::<Test>::NotApplied);
+		assert!(removed().is_empty());
+
+		assert_ok!(Reviewers::revoke(Origin::root(), 2));
+		assert_ok!(Reviewers::withdraw(Origin::signed(1)));
+		assert_eq!(removed(), vec![2, 1]);
+	});
+}
+
+#[test]
+fn approved_in_field_only_contains_approved_reviewers_of_the_field() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Reviewers::apply(Origin::signed(3), b"cred".to_vec(), vec![0, 1]));
+		assert_ok!(Reviewers::apply(Origin::signed(2), b"cred".to_vec(), vec![0]));
+		assert_ok!(Reviewers::approve(Origin::root(), 3));
+
+		assert!(<ApprovedInField<Test, Physics> as Contains<u64>>::contains(&1));
+		assert!(!<ApprovedInField<Test, Physics> as Contains<u64>>::contains(&2));
+		assert!(!<ApprovedInField<Test, Medicine> as Contains<u64>>::contains(&1));
+		assert_eq!(ApprovedInField::<Test, Physics>::sorted_members(), vec![1, 3]);
+		assert_eq!(ApprovedInField::<Test, Medicine>::sorted_members(), vec![3]);
+	});
+}
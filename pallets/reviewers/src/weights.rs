@@ -0,0 +1,75 @@
+//! Weights for pallet_reviewers
+//!
+//! These weights are not benchmarked yet. The execution times are conservative estimates, with a
+//! component for every byte and item a call handles, and the storage reads and writes are the
+//! ones a call makes in the worst case, counted from the code. Replace this file with the
+//! output of the benchmark CLI once the benchmarks in `benchmarking.rs` are run on reference
+//! hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_reviewers.
+pub trait WeightInfo {
+	fn apply(n: u32, f: u32, ) -> Weight;
+	fn withdraw() -> Weight;
+	fn approve() -> Weight;
+	fn revoke() -> Weight;
+}
+
+/// Weights for pallet_reviewers using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn apply(n: u32, f: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((110_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn withdraw() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	fn approve() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn revoke() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn apply(n: u32, f: u32, ) -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add((110_000 as Weight).saturating_mul(f as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn withdraw() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn approve() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn revoke() -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
@@ -0,0 +1,70 @@
+//! Benchmarking setup for pallet-sortition
+
+use super::*;
+
+use crate::Pallet as Sortition;
+use frame_benchmarking::{account, benchmarks_instance_pallet, impl_benchmark_test_suite};
+use frame_support::{
+	traits::{EnsureOrigin, Get, SortedMembers},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_std::{convert::TryInto, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// Fills the pool with `count` candidates.
+fn fill_pool<T: Config<I>, I: 'static>(count: u32) {
+	let mut pool: Vec<T::AccountId> = (0..count).map(|i| account("reviewer", i, SEED)).collect();
+	pool.iter().for_each(T::Candidates::add);
+	pool.sort();
+	let pool: BoundedVec<_, T::MaxPoolSize> = pool.try_into().unwrap_or_default();
+	Pool::<T, I>::put(pool);
+}
+
+benchmarks_instance_pallet! {
+	join_pool {
+		let p in 0 .. T::MaxPoolSize::get() - 1;
+		fill_pool::<T, I>(p);
+		let caller: T::AccountId = account("joining", 0, SEED);
+		T::Candidates::add(&caller);
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(Pool::<T, I>::get().contains(&caller));
+	}
+
+	leave_pool {
+		let p in 1 .. T::MaxPoolSize::get();
+		fill_pool::<T, I>(p);
+		let caller = Pool::<T, I>::get()[0].clone();
+	}: _(RawOrigin::Signed(caller.clone()))
+	verify {
+		assert!(!Pool::<T, I>::get().contains(&caller));
+	}
+
+	remove_reviewer {
+		let p in T::PanelSize::get() .. T::MaxPoolSize::get();
+		fill_pool::<T, I>(p);
+		Sortition::<T, I>::draw(T::DrawOrigin::successful_origin())?;
+		let who = Panel::<T, I>::get()[0].clone();
+	}: {
+		Sortition::<T, I>::remove_reviewer(&who);
+	}
+	verify {
+		assert!(!Pool::<T, I>::get().contains(&who));
+		assert!(!T::MembershipChanged::sorted_members().contains(&who));
+	}
+
+	draw {
+		let p in T::PanelSize::get() .. T::MaxPoolSize::get();
+		fill_pool::<T, I>(p);
+		let origin = T::DrawOrigin::successful_origin();
+	}: {
+		Sortition::<T, I>::draw(origin)?;
+	}
+	verify {
+		assert_eq!(SelectionCount::<T, I>::get(), 1);
+	}
+}
+
+impl_benchmark_test_suite!(Sortition, crate::mock::new_test_ext(), crate::mock::Test);
@@ -0,0 +1,350 @@
+//! # Sortition Pallet
+//!
+//! Draws the members of a collective at random from a pool of reviewers, so that the people
+//! voting on content rotate and no fixed group decides every review.
+//!
+//! Reviewers enter the pool with `join_pool` and leave it with `leave_pool`. Only `Candidates`,
+//! e.g. the approved reviewers of a field, can join the pool or be drawn from it. A panel of
+//! `PanelSize` reviewers is drawn every `RotationPeriod` blocks, or whenever `DrawOrigin` calls
+//! `draw`, e.g. for a new content item. The panel replaces the members of the target collective
+//! through `ChangeMembers`.
+//!
+//! Reviewers who stop being candidates are skipped by later draws. The runtime can remove them
+//! from the pool, the panel and the collective right away with `Pallet::remove_reviewer`, e.g.
+//! once their approval is revoked.
+//!
+//! Every draw is recorded in `Selections` along with the random seed it used. Given the
+//! candidates in the pool at the block of the draw, anyone can recompute the panel with
+//! `Pallet::sample`.
+//!
+//! Draws are only as unpredictable as the configured `Randomness`. The randomness of
+//! `pallet_randomness_collective_flip` can be influenced by block producers, so a runtime that
+//! needs stronger guarantees should plug in a VRF based source.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{ChangeMembers, InitializeMembers, PalletInfoAccess, Randomness, SortedMembers},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Hash, TrailingZeroInput, Zero};
+	use sp_std::{convert::TryInto, vec::Vec};
+
+	/// Index of a draw.
+	pub type SelectionIndex = u32;
+
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>: frame_system::Config {
+		type Event: From<Event<Self, I>> + IsType<<Self as frame_system::Config>::Event>;
+		/// Source of randomness for the draws.
+		type Randomness: Randomness<Self::Hash, Self::BlockNumber>;
+		/// The collective receiving the drawn panel as its members, replacing its current members.
+		type MembershipChanged: ChangeMembers<Self::AccountId> + SortedMembers<Self::AccountId>;
+		/// The collective receiving the genesis panel as its members.
+		type MembershipInitialized: InitializeMembers<Self::AccountId>;
+		/// Accounts that may join the pool and be drawn.
+		type Candidates: SortedMembers<Self::AccountId>;
+		/// Origin allowed to draw a new panel outside of the rotation.
+		type DrawOrigin: EnsureOrigin<Self::Origin>;
+		/// Number of reviewers drawn into a panel.
+		#[pallet::constant]
+		type PanelSize: Get<u32>;
+		/// Maximum number of reviewers in the pool.
+		#[pallet::constant]
+		type MaxPoolSize: Get<u32>;
+		/// Number of blocks between automatic draws. Zero disables them.
+		#[pallet::constant]
+		type RotationPeriod: Get<Self::BlockNumber>;
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	#[pallet::generate_storage_info]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	pub type SelectionOf<T, I = ()> = Selection<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::BlockNumber,
+		<T as frame_system::Config>::Hash,
+		<T as Config<I>>::PanelSize,
+	>;
+
+	#[derive(Encode, Decode, Clone, Eq, PartialEq, RuntimeDebug, MaxEncodedLen)]
+	/// A recorded draw of a panel.
+	pub struct Selection<AccountId, BlockNumber, Hash, PanelLimit: Get<u32>> {
+		/// Block in which the panel was drawn.
+		pub drawn_at: BlockNumber,
+		/// The random seed the panel was drawn with.
+		pub seed: Hash,
+		/// The drawn reviewers, in the order they were drawn.
+		pub members: BoundedVec<AccountId, PanelLimit>,
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn pool)]
+	/// Reviewers who can be drawn, sorted.
+	pub type Pool<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxPoolSize>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn panel)]
+	/// The current panel, sorted.
+	pub type Panel<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, BoundedVec<T::AccountId, T::PanelSize>, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn selection_count)]
+	/// Number of panels drawn so far.
+	pub type SelectionCount<T: Config<I>, I: 'static = ()> =
+		StorageValue<_, SelectionIndex, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn selection)]
+	/// Every panel drawn, so that the draws can be audited.
+	pub type Selections<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, SelectionIndex, SelectionOf<T, I>>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config<I>, I: 'static = ()> {
+		/// Reviewers in the pool at genesis, at least `PanelSize` of them.
+		pub pool: Vec<T::AccountId>,
+		/// The panel at genesis, until the first draw.
+		pub panel: Vec<T::AccountId>,
+		pub phantom: PhantomData<I>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config<I>, I: 'static> Default for GenesisConfig<T, I> {
+		fn default() -> Self {
+			Self { pool: Vec::new(), panel: Vec::new(), phantom: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config<I>, I: 'static> GenesisBuild<T, I> for GenesisConfig<T, I> {
+		fn build(&self) {
+			let mut pool = self.pool.clone();
+			pool.sort();
+			pool.dedup();
+			assert!(pool.len() == self.pool.len(), "Pool cannot contain duplicate accounts.");
+			assert!(
+				pool.len() >= T::PanelSize::get() as usize,
+				"Pool cannot be smaller than PanelSize."
+			);
+			let pool: BoundedVec<_, T::MaxPoolSize> =
+				pool.try_into().expect("Pool cannot be larger than MaxPoolSize.");
+			Pool::<T, I>::put(pool);
+
+			let mut panel = self.panel.clone();
+			panel.sort();
+			T::MembershipInitialized::initialize_members(&panel);
+			let panel: BoundedVec<_, T::PanelSize> =
+				panel.try_into().expect("Panel cannot be larger than PanelSize.");
+			Panel::<T, I>::put(panel);
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::metadata(T::AccountId = "AccountId")]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A reviewer joined the pool. \[reviewer\]
+		JoinedPool(T::AccountId),
+		/// A reviewer left the pool. \[reviewer\]
+		LeftPool(T::AccountId),
+		/// A new panel was drawn. \[selection_index, members\]
+		PanelDrawn(SelectionIndex, Vec<T::AccountId>),
+		/// A reviewer was removed from the pool and the panel. \[reviewer\]
+		Removed(T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The account is already in the pool.
+		AlreadyInPool,
+		/// The account is not in the pool.
+		NotInPool,
+		/// The pool already has `MaxPoolSize` reviewers.
+		PoolFull,
+		/// The pool has fewer than `PanelSize` candidates.
+		PoolTooSmall,
+		/// The account is not a candidate.
+		NotCandidate,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let period = T::RotationPeriod::get();
+			if period.is_zero() || !(n % period).is_zero() {
+				return 0
+			}
+			// If the pool is too small, the current panel stays in place until the next rotation.
+			let _ = Self::do_draw();
+			T::WeightInfo::draw(T::MaxPoolSize::get())
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		#[pallet::weight(T::WeightInfo::join_pool(T::MaxPoolSize::get()))]
+		/// Enters the pool of reviewers who can be drawn.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The reviewer.
+		pub fn join_pool(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(T::Candidates::contains(&who), Error::<T, I>::NotCandidate);
+			Pool::<T, I>::try_mutate(|pool| -> DispatchResult {
+				let index = pool.binary_search(&who).err().ok_or(Error::<T, I>::AlreadyInPool)?;
+				pool.try_insert(index, who.clone()).map_err(|_| Error::<T, I>::PoolFull)?;
+				Ok(())
+			})?;
+			Self::deposit_event(Event::JoinedPool(who));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::leave_pool(T::MaxPoolSize::get()))]
+		/// Leaves the pool of reviewers. A reviewer on the current panel stays on it until the
+		/// next draw.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - The reviewer.
+		pub fn leave_pool(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Pool::<T, I>::try_mutate(|pool| -> DispatchResult {
+				let index = pool.binary_search(&who).map_err(|_| Error::<T, I>::NotInPool)?;
+				pool.remove(index);
+				Ok(())
+			})?;
+			Self::deposit_event(Event::LeftPool(who));
+			Ok(())
+		}
+
+		#[pallet::weight(T::WeightInfo::draw(T::MaxPoolSize::get()))]
+		/// Draws a new panel from the pool outside of the rotation.
+		///
+		/// # Arguments
+		///
+		/// * `origin` - Origin of the request, e.g. a passed motion of a collective.
+		pub fn draw(origin: OriginFor<T>) -> DispatchResult {
+			T::DrawOrigin::ensure_origin(origin)?;
+			Self::do_draw()?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Draws a panel from the candidates in the pool, hands it to the collective and records
+		/// the draw. Reviewers who stopped being candidates since joining are skipped.
+		fn do_draw() -> Result<SelectionIndex, DispatchError> {
+			let pool: Vec<T::AccountId> = Pool::<T, I>::get()
+				.into_inner()
+				.into_iter()
+				.filter(T::Candidates::contains)
+				.collect();
+			let size = T::PanelSize::get() as usize;
+			ensure!(pool.len() >= size, Error::<T, I>::PoolTooSmall);
+
+			let index = SelectionCount::<T, I>::get();
+			// The pallet name keeps instances drawing in the same block from sharing a seed.
+			let (seed, _) = T::Randomness::random(&(Self::name().as_bytes(), index).encode());
+			let drawn = Self::sample(pool, size, &seed);
+
+			let mut members = drawn.clone();
+			members.sort();
+			// The collective may have been given other members since the last draw.
+			let old_members = T::MembershipChanged::sorted_members();
+			T::MembershipChanged::set_members_sorted(&members, &old_members);
+			// Neither can fail, no more than `PanelSize` reviewers are drawn.
+			let panel: BoundedVec<_, T::PanelSize> = members.try_into().unwrap_or_default();
+			let recorded: BoundedVec<_, T::PanelSize> =
+				drawn.clone().try_into().unwrap_or_default();
+			Panel::<T, I>::put(panel);
+			Selections::<T, I>::insert(
+				index,
+				Selection {
+					drawn_at: frame_system::Pallet::<T>::block_number(),
+					seed,
+					members: recorded,
+				},
+			);
+			SelectionCount::<T, I>::put(index.saturating_add(1));
+
+			Self::deposit_event(Event::PanelDrawn(index, drawn));
+			Ok(index)
+		}
+
+		/// Removes `who` from the pool, the current panel and the members of the collective, along
+		/// with their votes on its motions. Returns the weight consumed.
+		pub fn remove_reviewer(who: &T::AccountId) -> Weight {
+			let mut removed = Pool::<T, I>::mutate(|pool| match pool.binary_search(who) {
+				Ok(index) => {
+					pool.remove(index);
+					true
+				},
+				Err(_) => false,
+			});
+			Panel::<T, I>::mutate(|panel| {
+				if let Ok(index) = panel.binary_search(who) {
+					panel.remove(index);
+					removed = true;
+				}
+			});
+			let mut members = T::MembershipChanged::sorted_members();
+			if let Ok(index) = members.binary_search(who) {
+				members.remove(index);
+				T::MembershipChanged::change_members_sorted(&[], &[who.clone()], &members);
+				removed = true;
+			}
+			if removed {
+				Self::deposit_event(Event::Removed(who.clone()));
+			}
+			T::WeightInfo::remove_reviewer(T::MaxPoolSize::get())
+		}
+
+		/// Picks `count` distinct accounts from `pool`, in the order they are drawn.
+		///
+		/// The `i`th account is picked from the accounts not picked yet, at a position derived
+		/// from the hash of `seed` and `i`. With the same pool and seed this always yields the same
+		/// accounts, which is what makes the recorded draws auditable.
+		pub fn sample(
+			mut pool: Vec<T::AccountId>,
+			count: usize,
+			seed: &T::Hash,
+		) -> Vec<T::AccountId> {
+			let count = count.min(pool.len());
+			for i in 0..count {
+				let entropy = T::Hashing::hash_of(&(seed, i as u32));
+				let random = u32::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+					.unwrap_or_default();
+				let picked = i + random as usize % (pool.len() - i);
+				pool.swap(i, picked);
+			}
+			pool.truncate(count);
+			pool
+		}
+	}
+}
@@ -0,0 +1,164 @@
+use crate as pallet_sortition;
+use frame_support::{
+	parameter_types,
+	traits::{Randomness, SortedMembers},
+};
+use frame_system as system;
+use sp_core::H256;
+use std::cell::RefCell;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, Hash, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Sortition: pallet_sortition::{Pallet, Call, Storage, Event<T>, Config<T>},
+		Panel: pallet_collective::<Instance1>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const PanelMotionDuration: u64 = 3;
+	pub const PanelMaxProposals: u32 = 100;
+	pub const PanelMaxMembers: u32 = 10;
+}
+
+pub type PanelCollective = pallet_collective::Instance1;
+
+impl pallet_collective::Config<PanelCollective> for Test {
+	type Origin = Origin;
+	type Proposal = Call;
+	type Event = Event;
+	type MotionDuration = PanelMotionDuration;
+	type MaxProposals = PanelMaxProposals;
+	type MaxMembers = PanelMaxMembers;
+	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MembershipFilter = frame_support::traits::Everything;
+	type OnProposalClosed = ();
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = ();
+	type VoteWeight = ();
+	type MaxDelegationDepth = ();
+	type WeightInfo = ();
+}
+
+/// Randomness that changes with the subject and the block number.
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+	fn random(subject: &[u8]) -> (H256, u64) {
+		let block_number = System::block_number();
+		(BlakeTwo256::hash_of(&(subject, block_number)), block_number)
+	}
+}
+
+thread_local! {
+	static CANDIDATES: RefCell<Vec<u64>> = RefCell::new((1..=6).collect());
+}
+
+/// Accounts 1 to 6, unless removed with `remove_candidate`.
+pub struct Candidates;
+
+impl SortedMembers<u64> for Candidates {
+	fn sorted_members() -> Vec<u64> {
+		CANDIDATES.with(|candidates| candidates.borrow().clone())
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn add(who: &u64) {
+		CANDIDATES.with(|candidates| {
+			let mut candidates = candidates.borrow_mut();
+			if let Err(index) = candidates.binary_search(who) {
+				candidates.insert(index, *who);
+			}
+		})
+	}
+}
+
+pub fn remove_candidate(who: u64) {
+	CANDIDATES.with(|candidates| candidates.borrow_mut().retain(|c| *c != who));
+}
+
+parameter_types! {
+	pub const PanelSize: u32 = 2;
+	pub const MaxPoolSize: u32 = 5;
+	pub const RotationPeriod: u64 = 10;
+}
+
+impl pallet_sortition::Config for Test {
+	type Event = Event;
+	type Randomness = TestRandomness;
+	type MembershipChanged = Panel;
+	type MembershipInitialized = Panel;
+	type Candidates = Candidates;
+	type DrawOrigin = frame_system::EnsureRoot<u64>;
+	type PanelSize = PanelSize;
+	type MaxPoolSize = MaxPoolSize;
+	type RotationPeriod = RotationPeriod;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_sortition::GenesisConfig::<Test> {
+		pool: vec![4, 1, 3, 2],
+		panel: vec![2, 1],
+		phantom: Default::default(),
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext: sp_io::TestExternalities = t.into();
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Runs the hooks of every block up to and including `n`.
+pub fn run_to_block(n: u64) {
+	use frame_support::traits::Hooks;
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		Sortition::on_initialize(System::block_number());
+	}
+}
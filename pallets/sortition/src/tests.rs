@@ -0,0 +1,170 @@
+use crate::{mock::*, Error};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchError, traits::GenesisBuild};
+use sp_runtime::traits::{BlakeTwo256, Hash};
+
+#[test]
+fn genesis_initializes_the_pool_and_the_collective() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Sortition::pool().into_inner(), vec![1, 2, 3, 4]);
+		assert_eq!(Sortition::panel().into_inner(), vec![1, 2]);
+		assert_eq!(Panel::members(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn reviewers_join_and_leave_the_pool() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Sortition::join_pool(Origin::signed(7)), Error::<Test>::NotCandidate);
+		assert_ok!(Sortition::join_pool(Origin::signed(5)));
+		System::assert_last_event(Event::Sortition(crate::Event::JoinedPool(5)));
+		assert_noop!(Sortition::join_pool(Origin::signed(5)), Error::<Test>::AlreadyInPool);
+		assert_noop!(Sortition::join_pool(Origin::signed(6)), Error::<Test>::PoolFull);
+
+		assert_ok!(Sortition::leave_pool(Origin::signed(3)));
+		System::assert_last_event(Event::Sortition(crate::Event::LeftPool(3)));
+		assert_noop!(Sortition::leave_pool(Origin::signed(3)), Error::<Test>::NotInPool);
+		assert_eq!(Sortition::pool().into_inner(), vec![1, 2, 4, 5]);
+	});
+}
+
+#[test]
+fn draws_replace_the_members_of_the_collective() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Sortition::draw(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(Sortition::draw(Origin::root()));
+
+		let selection = Sortition::selection(0).unwrap();
+		assert_eq!(selection.drawn_at, 1);
+		assert_eq!(selection.members.len(), 2);
+		let mut panel = selection.members.clone().into_inner();
+		panel.sort();
+		assert_eq!(Sortition::panel().into_inner(), panel);
+		assert_eq!(Panel::members(), panel);
+		assert_eq!(Sortition::selection_count(), 1);
+		System::assert_last_event(Event::Sortition(crate::Event::PanelDrawn(
+			0,
+			selection.members.into_inner(),
+		)));
+	});
+}
+
+#[test]
+fn draws_replace_members_set_on_the_collective() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Panel::set_members(Origin::root(), vec![5, 6], None, 2));
+		let proposal = Call::System(frame_system::Call::remark(vec![]));
+		let proposal_len = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Panel::propose(Origin::signed(5), 2, Box::new(proposal), proposal_len));
+
+		// Neither member is in the pool, so both leave the collective along with their votes.
+		assert_ok!(Sortition::draw(Origin::root()));
+		assert_eq!(Panel::members(), Sortition::panel().into_inner());
+		assert!(Panel::voting(hash).unwrap().ayes.is_empty());
+	});
+}
+
+#[test]
+#[should_panic(expected = "Pool cannot be smaller than PanelSize.")]
+fn genesis_pool_must_fill_a_panel() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	crate::GenesisConfig::<Test> { pool: vec![1], panel: vec![1], phantom: Default::default() }
+		.assimilate_storage(&mut t)
+		.unwrap();
+}
+
+#[test]
+fn draws_can_be_recomputed_from_the_seed() {
+	new_test_ext().execute_with(|| {
+		let pool = Sortition::pool().into_inner();
+		assert_ok!(Sortition::draw(Origin::root()));
+
+		let selection = Sortition::selection(0).unwrap();
+		assert_eq!(Sortition::sample(pool, 2, &selection.seed), selection.members.into_inner());
+	});
+}
+
+#[test]
+fn samples_are_distinct_members_of_the_pool() {
+	new_test_ext().execute_with(|| {
+		let pool: Vec<u64> = (0..20).collect();
+		for seed in 0..50u64 {
+			let seed = sp_core::H256::from_low_u64_be(seed);
+			let mut sample = Sortition::sample(pool.clone(), 5, &seed);
+			assert_eq!(sample.len(), 5);
+			assert!(sample.iter().all(|a| pool.contains(a)));
+			sample.sort();
+			sample.dedup();
+			assert_eq!(sample.len(), 5);
+		}
+		assert_eq!(Sortition::sample(vec![1, 2], 5, &Default::default()).len(), 2);
+	});
+}
+
+#[test]
+fn panels_rotate_every_period() {
+	new_test_ext().execute_with(|| {
+		run_to_block(9);
+		assert_eq!(Sortition::selection_count(), 0);
+		run_to_block(10);
+		assert_eq!(Sortition::selection_count(), 1);
+		assert_eq!(Sortition::selection(0).unwrap().drawn_at, 10);
+		run_to_block(20);
+		assert_eq!(Sortition::selection_count(), 2);
+		// Draws in different blocks use different seeds.
+		assert_ne!(Sortition::selection(0).unwrap().seed, Sortition::selection(1).unwrap().seed);
+	});
+}
+
+#[test]
+fn a_small_pool_keeps_the_current_panel() {
+	new_test_ext().execute_with(|| {
+		for reviewer in 2..=4 {
+			assert_ok!(Sortition::leave_pool(Origin::signed(reviewer)));
+		}
+		assert_noop!(Sortition::draw(Origin::root()), Error::<Test>::PoolTooSmall);
+
+		run_to_block(10);
+		assert_eq!(Sortition::selection_count(), 0);
+		assert_eq!(Panel::members(), vec![1, 2]);
+	});
+}
+
+#[test]
+fn only_candidates_are_drawn() {
+	new_test_ext().execute_with(|| {
+		remove_candidate(1);
+		remove_candidate(2);
+		for _ in 0..10 {
+			assert_ok!(Sortition::draw(Origin::root()));
+			assert_eq!(Panel::members(), vec![3, 4]);
+		}
+
+		remove_candidate(3);
+		assert_noop!(Sortition::draw(Origin::root()), Error::<Test>::PoolTooSmall);
+	});
+}
+
+#[test]
+fn removed_reviewers_leave_the_pool_the_panel_and_the_collective() {
+	new_test_ext().execute_with(|| {
+		let proposal = Call::System(frame_system::Call::remark(vec![]));
+		let proposal_len = proposal.using_encoded(|p| p.len() as u32);
+		let hash = BlakeTwo256::hash_of(&proposal);
+		assert_ok!(Panel::propose(Origin::signed(1), 2, Box::new(proposal), proposal_len));
+		assert_ok!(Panel::vote(Origin::signed(2), hash, 0, true));
+
+		Sortition::remove_reviewer(&2);
+		System::assert_last_event(Event::Sortition(crate::Event::Removed(2)));
+		assert_eq!(Sortition::pool().into_inner(), vec![1, 3, 4]);
+		assert_eq!(Sortition::panel().into_inner(), vec![1]);
+		assert_eq!(Panel::members(), vec![1]);
+		assert_eq!(Panel::voting(hash).unwrap().ayes, vec![1]);
+
+		// Reviewers outside the pool and the panel are left alone.
+		let events = System::events().len();
+		Sortition::remove_reviewer(&6);
+		assert_eq!(System::events().len(), events);
+	});
+}
@@ -0,0 +1,82 @@
+//! Weights for pallet_sortition
+//!
+//! These weights are not benchmarked yet. The execution times are conservative estimates, with a
+//! component for every byte and item a call handles, and the storage reads and writes are the
+//! ones a call makes in the worst case, counted from the code. Replace this file with the
+//! output of the benchmark CLI once the benchmarks in `benchmarking.rs` are run on reference
+//! hardware.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_sortition.
+pub trait WeightInfo {
+	fn join_pool(p: u32, ) -> Weight;
+	fn leave_pool(p: u32, ) -> Weight;
+	fn remove_reviewer(p: u32, ) -> Weight;
+	fn draw(p: u32, ) -> Weight;
+}
+
+/// Weights for pallet_sortition using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn join_pool(p: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((90_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn leave_pool(p: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((85_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn remove_reviewer(p: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((90_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Every reviewer in the pool is checked to still be a candidate.
+	fn draw(p: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((4_600_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn join_pool(p: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((90_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn leave_pool(p: u32, ) -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add((85_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn remove_reviewer(p: u32, ) -> Weight {
+		(30_000_000 as Weight)
+			.saturating_add((90_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	fn draw(p: u32, ) -> Weight {
+		(61_000_000 as Weight)
+			.saturating_add((4_600_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+	}
+}
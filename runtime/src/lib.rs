@@ -11,7 +11,11 @@ use pallet_grandpa::{
 };
 use sp_api::impl_runtime_apis;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::{crypto::KeyTypeId, OpaqueMetadata};
+use sp_core::{
+	crypto::KeyTypeId,
+	u32_trait::{_1, _2},
+	OpaqueMetadata,
+};
 use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
@@ -24,6 +28,7 @@ use sp_version::NativeVersion;
 use sp_version::RuntimeVersion;
 
 use pallet_collective;
+use pallet_reviewers::FieldId;
 
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
@@ -33,7 +38,7 @@ pub use frame_support::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -64,6 +69,9 @@ pub type Index = u32;
 /// A hash of some data used by the chain.
 pub type Hash = sp_core::H256;
 
+/// Id of content submitted for review.
+pub type ContentId = u32;
+
 /// Opaque types. These are used by the CLI to instantiate machinery that don't need to know
 /// the specifics of the runtime. They can then be made to be agnostic over specific formats
 /// of data like extrinsics, allowing for them to continue syncing the network through upgrades
@@ -284,6 +292,12 @@ impl pallet_collective::Config<CouncilCollective> for Runtime {
 	type MaxProposals = CouncilMaxProposals;
 	type MaxMembers = CouncilMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MembershipFilter = frame_support::traits::Everything;
+	type OnProposalClosed = ();
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = ();
+	type VoteWeight = ();
+	type MaxDelegationDepth = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -291,6 +305,10 @@ parameter_types! {
 	pub const PanelMotionDuration: BlockNumber = 5 * DAYS;
 	pub const PanelMaxProposals: u32 = 200;
 	pub const PanelMaxMembers: u32 = 50;
+	pub const PanelVotingMode: pallet_collective::VotingMode<BlockNumber> =
+		pallet_collective::VotingMode::CommitReveal { commit: 3 * DAYS, reveal: 2 * DAYS };
+	pub const PanelQuorum: Perbill = Perbill::from_percent(60);
+	pub const PanelMaxDelegationDepth: u32 = 3;
 }
 
 type PanelCollective = pallet_collective::Instance2;
@@ -303,6 +321,17 @@ impl pallet_collective::Config<PanelCollective> for Runtime {
 	type MaxProposals = PanelMaxProposals;
 	type MaxMembers = PanelMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	/// Only approved reviewers of the Panel's field can be made members.
+	type MembershipFilter = pallet_reviewers::ApprovedInField<Runtime, PanelField>;
+	type OnProposalClosed = PublicaFides;
+	/// Panel members commit to their votes first, so that nobody votes after seeing the others.
+	type VotingMode = PanelVotingMode;
+	/// A verdict needs most of the panel to take part, for or against it.
+	type Quorum = PanelQuorum;
+	/// Reviewers with more reputation have more say in verdicts.
+	type VoteWeight = PublicaFides;
+	/// Reviewers on leave can leave their vote to another reviewer.
+	type MaxDelegationDepth = PanelMaxDelegationDepth;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -322,6 +351,13 @@ impl pallet_collective::Config<CommitteeCollective> for Runtime {
 	type MaxProposals = PanelMaxProposals;
 	type MaxMembers = PanelMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	/// Only approved reviewers of the Committee's field can be made members.
+	type MembershipFilter = pallet_reviewers::ApprovedInField<Runtime, CommitteeField>;
+	type OnProposalClosed = PublicaFides;
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = PanelQuorum;
+	type VoteWeight = PublicaFides;
+	type MaxDelegationDepth = PanelMaxDelegationDepth;
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
@@ -341,13 +377,181 @@ impl pallet_collective::Config<BoardCollective> for Runtime {
 	type MaxProposals = PanelMaxProposals;
 	type MaxMembers = PanelMaxMembers;
 	type DefaultVote = pallet_collective::PrimeDefaultVote;
+	type MembershipFilter = frame_support::traits::Everything;
+	type OnProposalClosed = ();
+	type VotingMode = pallet_collective::OpenVoting;
+	type Quorum = ();
+	type VoteWeight = ();
+	type MaxDelegationDepth = ();
 	type WeightInfo = pallet_collective::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const ReviewerApplicationDeposit: Balance = 10_000_000_000_000;
+	pub const ReviewerMaxCidLength: u32 = 128;
+	pub const ReviewerMaxFields: u32 = 16;
+	/// Field of expertise of the Panel, which decides whether claims are objective statements.
+	pub const PanelField: FieldId = 0;
+	/// Field of expertise of the Committee, which decides whether objective claims are true.
+	pub const CommitteeField: FieldId = 1;
+}
+
+impl pallet_reviewers::Config for Runtime {
+	type Event = Event;
+	/// The Board attests the credentials of reviewers.
+	type AttestOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, BoardCollective>;
+	type Currency = Balances;
+	type ApplicationDeposit = ReviewerApplicationDeposit;
+	type OnRemoved = RemoveFromPanels;
+	type MaxCidLength = ReviewerMaxCidLength;
+	type MaxFields = ReviewerMaxFields;
+	type WeightInfo = pallet_reviewers::weights::SubstrateWeight<Runtime>;
+}
+
+/// Removes reviewers who leave the registry from the pools, the Panel and the Committee right away,
+/// rather than at the next draw.
+pub struct RemoveFromPanels;
+
+impl pallet_reviewers::OnReviewerRemoved<AccountId> for RemoveFromPanels {
+	fn on_reviewer_removed(who: &AccountId) -> Weight {
+		PanelSortition::remove_reviewer(who)
+			.saturating_add(CommitteeSortition::remove_reviewer(who))
+	}
+
+	fn max_weight() -> Weight {
+		use pallet_sortition::WeightInfo;
+		let remove_weight = pallet_sortition::weights::SubstrateWeight::<Runtime>::remove_reviewer;
+		remove_weight(PanelSortitionMaxPool::get())
+			.saturating_add(remove_weight(CommitteeSortitionMaxPool::get()))
+	}
+}
+
+parameter_types! {
+	pub const PanelSortitionSize: u32 = 5;
+	pub const PanelSortitionMaxPool: u32 = 200;
+	pub const PanelSortitionRotation: BlockNumber = 7 * DAYS;
+}
+
+/// Draws the Panel from the reviewers who joined its pool.
+impl pallet_sortition::Config<pallet_sortition::Instance1> for Runtime {
+	type Event = Event;
+	type Randomness = RandomnessCollectiveFlip;
+	type MembershipChanged = Panel;
+	type MembershipInitialized = Panel;
+	type Candidates = pallet_reviewers::ApprovedInField<Runtime, PanelField>;
+	/// The Council can draw a new Panel outside of the weekly rotation.
+	type DrawOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+	type PanelSize = PanelSortitionSize;
+	type MaxPoolSize = PanelSortitionMaxPool;
+	type RotationPeriod = PanelSortitionRotation;
+	type WeightInfo = pallet_sortition::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const CommitteeSortitionSize: u32 = 7;
+	pub const CommitteeSortitionMaxPool: u32 = 200;
+	pub const CommitteeSortitionRotation: BlockNumber = 7 * DAYS;
+}
+
+/// Draws the Committee from the reviewers who joined its pool.
+impl pallet_sortition::Config<pallet_sortition::Instance2> for Runtime {
+	type Event = Event;
+	type Randomness = RandomnessCollectiveFlip;
+	type MembershipChanged = Committee;
+	type MembershipInitialized = Committee;
+	type Candidates = pallet_reviewers::ApprovedInField<Runtime, CommitteeField>;
+	/// The Council can draw a new Committee outside of the weekly rotation.
+	type DrawOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+	type PanelSize = CommitteeSortitionSize;
+	type MaxPoolSize = CommitteeSortitionMaxPool;
+	type RotationPeriod = CommitteeSortitionRotation;
+	type WeightInfo = pallet_sortition::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ContentDepositBase: Balance = 1_000_000_000_000;
+	pub const ContentDepositPerByte: Balance = 1_000_000_000;
+	pub const ContentReviewPeriod: BlockNumber = 14 * DAYS;
+	pub const MaxUrlLength: u32 = 2048;
+	pub const MaxDoiLength: u32 = 256;
+	pub const MaxCidLength: u32 = 128;
+	pub const MaxClaimsPerContent: u32 = 64;
+	pub const MaxSourceAccounts: u32 = 16;
+	pub const MaxContentPerClaim: u32 = 32;
+	pub const ReputationGain: publicafides::Reputation = 10;
+	pub const ReputationDecay: Perbill = Perbill::from_percent(10);
+	pub const MaxVotersPerClaim: u32 = 100;
+	pub const PublicaFidesPalletId: PalletId = PalletId(*b"py/pfrwd");
+	pub const RewardPerClaim: Balance = 100_000_000_000;
+	pub const RewardInflation: Balance = 1_000_000_000;
+	pub const AppealBond: Balance = 10_000_000_000_000;
+	pub const MaxVerdicts: u32 = 4;
+	pub const MaxReReviewsPerBlock: u32 = 4;
+}
+
 /// Configure the publicafides in pallets/template.
 impl publicafides::Config for Runtime {
 	type Event = Event;
-	type ContentId = u32;
+	type ContentId = ContentId;
+	/// Anyone may submit content for review.
+	type ContentOrigin = frame_system::EnsureSigned<AccountId>;
+	/// Claims are recorded once a majority of the Panel approves the motion.
+	type ClaimOrigin = pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, PanelCollective>;
+	/// The Panel decides whether claims are objective statements.
+	type ObjectivityOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, PanelCollective>;
+	/// The Committee decides whether objective claims are true.
+	type VeracityOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CommitteeCollective>;
+	/// Content is scored by the ratio of accepted to rejected claims.
+	type ScoringPolicy = publicafides::AcceptedRatio;
+	/// The Council registers authors and publishers, vouching for the accounts linked to them.
+	type SourceOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+	type Currency = Balances;
+	type ContentDepositBase = ContentDepositBase;
+	type ContentDepositPerByte = ContentDepositPerByte;
+	/// Deposits of spam submissions fund the rewards of reviewers.
+	type SpamSlashed = PublicaFides;
+	/// The Council completes reviews and marks spam.
+	type ReviewOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+	/// Content is scored two weeks after its first claim was raised, leaving time for the
+	/// objectivity and veracity motions on its claims.
+	type ReviewPeriod = ContentReviewPeriod;
+	type MaxUrlLength = MaxUrlLength;
+	type MaxDoiLength = MaxDoiLength;
+	type MaxCidLength = MaxCidLength;
+	type MaxClaimsPerContent = MaxClaimsPerContent;
+	type MaxSourceAccounts = MaxSourceAccounts;
+	type MaxContentPerClaim = MaxContentPerClaim;
+	/// Reviewers gain reputation by voting in line with the final verdict on a claim, and lose a
+	/// tenth of it by voting against.
+	type ReputationGain = ReputationGain;
+	type ReputationDecay = ReputationDecay;
+	type MaxVotersPerClaim = MaxVotersPerClaim;
+	type PalletId = PublicaFidesPalletId;
+	/// Reviewers share up to a tenth of a unit for every finalized claim. Besides the slashed
+	/// deposits, the reward pot is topped up with a thousandth of a unit every block.
+	type RewardPerClaim = RewardPerClaim;
+	type RewardInflation = RewardInflation;
+	/// The Board decides appeals against verdicts. Appellants reserve ten units, and a claim can
+	/// be appealed up to three times.
+	type AppealOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, BoardCollective>;
+	type AppealBond = AppealBond;
+	type MaxVerdicts = MaxVerdicts;
+	/// The Board decides how long the verdicts on each category of claims stand.
+	type ReviewPeriodOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, BoardCollective>;
+	type MaxReReviewsPerBlock = MaxReReviewsPerBlock;
+	/// The Council approves the plain-language summaries of claims published to the media.
+	type PublicSummaryOrigin =
+		pallet_collective::EnsureProportionAtLeast<_1, _2, AccountId, CouncilCollective>;
+	type WeightInfo = publicafides::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -371,6 +575,9 @@ construct_runtime! {
 		Panel: pallet_collective::<Instance2>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
 		Committee: pallet_collective::<Instance3>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
 		Board: pallet_collective::<Instance4>::{Pallet, Call, Storage, Origin<T>, Event<T>, Config<T>},
+		Reviewers: pallet_reviewers::{Pallet, Call, Storage, Event<T>, Config<T>},
+		PanelSortition: pallet_sortition::<Instance1>::{Pallet, Call, Storage, Event<T>, Config<T>},
+		CommitteeSortition: pallet_sortition::<Instance2>::{Pallet, Call, Storage, Event<T>, Config<T>},
 	}
 }
 
@@ -392,6 +599,21 @@ pub type SignedExtra = (
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
+/// Moves the storage of the collectives from the prefixes of the `decl_storage` pallet, e.g.
+/// `Instance1Collective`, to their names in the runtime.
+pub struct CollectivesToPalletPrefix;
+
+impl frame_support::traits::OnRuntimeUpgrade for CollectivesToPalletPrefix {
+	fn on_runtime_upgrade() -> Weight {
+		use pallet_collective::migrations::v3::migrate;
+
+		migrate::<Runtime, CouncilCollective, _>("Instance1Collective")
+			.saturating_add(migrate::<Runtime, PanelCollective, _>("Instance2Collective"))
+			.saturating_add(migrate::<Runtime, CommitteeCollective, _>("Instance3Collective"))
+			.saturating_add(migrate::<Runtime, BoardCollective, _>("Instance4Collective"))
+	}
+}
+
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
 	Runtime,
@@ -399,6 +621,7 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPallets,
+	CollectivesToPalletPrefix,
 >;
 
 impl_runtime_apis! {
@@ -532,6 +755,28 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl publicafides_runtime_api::PublicaFidesApi<Block, ContentId> for Runtime {
+		fn content(content_id: ContentId) -> Option<publicafides::ContentInfo<ContentId>> {
+			PublicaFides::content_info(content_id)
+		}
+		fn claims_by_status(
+			status: publicafides::ClaimStatus,
+			start: publicafides::ClaimId,
+			count: u32,
+		) -> Vec<publicafides::ClaimInfo> {
+			PublicaFides::claims_with_status(status, start, count)
+		}
+		fn score(content_id: ContentId) -> Option<Permill> {
+			PublicaFides::content_score(content_id)
+		}
+		fn content_page(start: ContentId, count: u32) -> Vec<publicafides::ContentInfo<ContentId>> {
+			PublicaFides::content_page(start, count)
+		}
+		fn public_summaries(content_id: ContentId) -> Vec<publicafides::ClaimSummary> {
+			PublicaFides::public_summaries(content_id)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -546,8 +791,14 @@ impl_runtime_apis! {
 
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_balances, Balances);
+			// The Panel has the most expensive configuration of the collectives, which share their
+			// weights.
+			list_benchmark!(list, extra, pallet_collective, Panel);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
-			list_benchmark!(list, extra, publicafides, publicafides);
+			list_benchmark!(list, extra, publicafides, PublicaFides);
+			list_benchmark!(list, extra, pallet_reviewers, Reviewers);
+			list_benchmark!(list, extra, pallet_sortition, PanelSortition);
+			list_benchmark!(list, extra, pallet_sortition, CommitteeSortition);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -580,8 +831,12 @@ impl_runtime_apis! {
 
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
+			add_benchmark!(params, batches, pallet_collective, Panel);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
-			add_benchmark!(params, batches, publicafides, publicafides);
+			add_benchmark!(params, batches, publicafides, PublicaFides);
+			add_benchmark!(params, batches, pallet_reviewers, Reviewers);
+			add_benchmark!(params, batches, pallet_sortition, PanelSortition);
+			add_benchmark!(params, batches, pallet_sortition, CommitteeSortition);
 
 			if batches.is_empty() { return Err("Benchmark not found for this pallet.".into()) }
 			Ok(batches)